@@ -0,0 +1,39 @@
+//! Times printing a right-aligned process with and without the line cache.
+//! Run it with `cargo bench`. The cache skips padding each line again, so printing with it on should be faster.
+use std::time::{Duration, Instant};
+
+use grid_ui::grid::{DividerStrategy, Frame, HAlign};
+use grid_ui::out::OutToString;
+use grid_ui::process::DrawProcess;
+use grid_ui::trim::Truncate;
+
+const ROWS: usize = 200;
+const PRINTS: u32 = 500;
+
+fn process(cached: bool) -> DrawProcess {
+    let mut process = Frame::new(0, 0, 120, ROWS).next_frame().into_process_aligned(DividerStrategy::Beginning, HAlign::Right);
+    process.set_line_cache(cached);
+    for i in 0..ROWS {
+        process
+            .add_to_section(format!("Line number {}", i), &mut Truncate, grid_ui::grid::Alignment::Plus)
+            .expect("Every line fits");
+    }
+    process
+}
+
+fn time(process: &DrawProcess) -> Duration {
+    let start = Instant::now();
+    for _ in 0..PRINTS {
+        let mut output = String::new();
+        process.print(&mut OutToString, &mut output).expect("Printing to a string can't fail");
+        std::hint::black_box(output);
+    }
+    start.elapsed() / PRINTS
+}
+
+fn main() {
+    let uncached = time(&process(false));
+    let cached = time(&process(true));
+    println!("print without the line cache: {:?} per print", uncached);
+    println!("print with the line cache:    {:?} per print", cached);
+}
@@ -1,19 +1,66 @@
-use std::io::Stdout;
+use std::{
+    io::{Stdout, Write},
+    marker::PhantomData,
+};
 
-use crate::out::{Action, Handler};
+use crate::out::{Action, Color, Handler};
 
-use crossterm::{cursor::MoveTo, execute, queue, style::Print};
-/// A basic wrapper for crossterm. Turns this output into crossterm-based output.
-pub struct CrosstermHandler;
-impl CrosstermHandler {
-    /// Flushes any stray text into the terminal.
-    pub fn finish(out: &mut Stdout) -> Result<(), crossterm::ErrorKind> {
+use crossterm::{
+    cursor::{MoveDown, MoveLeft, MoveRight, MoveTo, MoveUp},
+    execute, queue,
+    style::{self, Print, ResetColor, SetBackgroundColor, SetForegroundColor},
+    terminal::{Clear, ClearType},
+};
+/**
+A basic wrapper for crossterm. Turns this output into crossterm-based output.
+Actions are queued, so nothing reaches the terminal until the handler is flushed.
+The output can be stdout (the default), or anything else that implements Write.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::crossterm::CrosstermHandler;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), crossterm::ErrorKind>{
+let mut process = grid::Frame::new(0, 0, 5, 1).next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Hi".to_string(), &mut Truncate, grid::Alignment::Plus);
+let mut handler = CrosstermHandler::new();
+let mut output: Vec<u8> = Vec::new();
+process.print(&mut handler, &mut output)?;
+handler.flush(&mut output)?;
+assert_eq!(output, b"\x1b[1;1HHi   ".to_vec());
+# Ok(())
+# }
+```
+*/
+pub struct CrosstermHandler<W: Write = Stdout> {
+    output: PhantomData<fn(&mut W)>,
+}
+impl<W: Write> CrosstermHandler<W> {
+    /// Creates a handler that writes to W.
+    pub fn new() -> CrosstermHandler<W> {
+        CrosstermHandler { output: PhantomData }
+    }
+    /// Flushes everything queued so far into the output.
+    /// # Errors
+    /// Returns an error if the output can't be written to.
+    pub fn flush(&mut self, out: &mut W) -> Result<(), crossterm::ErrorKind> {
+        execute!(out)
+    }
+    /// Flushes any stray text into the output.
+    /// # Errors
+    /// Returns an error if the output can't be written to.
+    pub fn finish(out: &mut W) -> Result<(), crossterm::ErrorKind> {
         execute!(out)
     }
 }
+impl<W: Write> Default for CrosstermHandler<W> {
+    fn default() -> Self {
+        CrosstermHandler::new()
+    }
+}
 
-impl Handler for CrosstermHandler {
-    type OutputDevice = Stdout;
+impl<W: Write> Handler for CrosstermHandler<W> {
+    type OutputDevice = W;
     type Error = crossterm::ErrorKind;
     fn handle(&mut self, out: &mut Self::OutputDevice, input: &Action) -> Result<(), Self::Error> {
         match input {
@@ -23,6 +70,70 @@ impl Handler for CrosstermHandler {
             Action::MoveTo(x, y) => {
                 queue!(out, MoveTo(*x as u16, *y as u16))
             }
+            Action::MoveBy(dx, dy) => {
+                // Terminals treat a move of 0 as a move of 1, so those are skipped.
+                if *dx > 0 {
+                    queue!(out, MoveRight(*dx as u16))?;
+                } else if *dx < 0 {
+                    queue!(out, MoveLeft(dx.unsigned_abs() as u16))?;
+                }
+                if *dy > 0 {
+                    queue!(out, MoveDown(*dy as u16))?;
+                } else if *dy < 0 {
+                    queue!(out, MoveUp(dy.unsigned_abs() as u16))?;
+                }
+                Ok(())
+            }
+            Action::Repeat(c, n) => {
+                queue!(out, Print(std::iter::repeat(*c).take(*n).collect::<String>()))
+            }
+            Action::Hyperlink { uri, text } => {
+                queue!(out, Print(format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", uri, text)))
+            }
+            Action::BeginSyncUpdate => {
+                queue!(out, Print("\x1b[?2026h"))
+            }
+            Action::EndSyncUpdate => {
+                queue!(out, Print("\x1b[?2026l"))
+            }
+            Action::SetColor(color) => {
+                queue!(out, SetForegroundColor(crossterm_color(*color)))
+            }
+            Action::SetBackground(color) => {
+                queue!(out, SetBackgroundColor(crossterm_color(*color)))
+            }
+            Action::ResetColor => {
+                queue!(out, ResetColor)
+            }
+            Action::Clear => {
+                queue!(out, Clear(ClearType::All))
+            }
+            Action::ClearLine => {
+                queue!(out, Clear(ClearType::CurrentLine))
+            }
         }
     }
 }
+#[doc(hidden)]
+/// Converts a color into the matching crossterm color.
+fn crossterm_color(color: Color) -> style::Color {
+    match color {
+        Color::Black => style::Color::Black,
+        Color::Red => style::Color::DarkRed,
+        Color::Green => style::Color::DarkGreen,
+        Color::Yellow => style::Color::DarkYellow,
+        Color::Blue => style::Color::DarkBlue,
+        Color::Magenta => style::Color::DarkMagenta,
+        Color::Cyan => style::Color::DarkCyan,
+        Color::White => style::Color::Grey,
+        Color::BrightBlack => style::Color::DarkGrey,
+        Color::BrightRed => style::Color::Red,
+        Color::BrightGreen => style::Color::Green,
+        Color::BrightYellow => style::Color::Yellow,
+        Color::BrightBlue => style::Color::Blue,
+        Color::BrightMagenta => style::Color::Magenta,
+        Color::BrightCyan => style::Color::Cyan,
+        Color::BrightWhite => style::Color::White,
+        Color::Rgb(r, g, b) => style::Color::Rgb { r, g, b },
+    }
+}
@@ -1,4 +1,13 @@
-use crate::process::DrawProcess;
+use std::{
+    error::Error,
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    out::{Action, Handler},
+    process::DrawProcess,
+};
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// This is a frame. It stores the terminal's size in a convenient place.
@@ -68,6 +77,29 @@ impl Frame {
             end_y: y_max,
         }
     }
+    /**
+    Divides the frame into rows * cols cells, like a table. The result is a list of rows from top to bottom,
+    and each row is a list of cells from left to right. The cells are as close to equal as possible,
+    with the rows and columns that can't be split evenly going to the earliest cells, so the cells cover the frame without gaps or overlaps.
+    Returns no cells if rows or cols is 0.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() {
+    let frame = Frame::new(0, 0, 100, 2);
+    let cells = frame.grid(2, 3);
+    assert_eq!(cells.len(), 2);
+    assert_eq!(cells[0].iter().map(|cell| (cell.start_x, cell.end_x)).collect::<Vec<_>>(), vec![(0, 34), (34, 67), (67, 100)]);
+    assert_eq!(cells[1][2], Grid {start_x: 67, start_y: 1, end_x: 100, end_y: 2});
+    # }
+    ```
+    */
+    pub fn grid(&self, rows: usize, cols: usize) -> Vec<Vec<Grid>> {
+        if cols == 0 {
+            return Vec::new();
+        }
+        self.grid.into_rows(rows).iter().map(|row| row.into_columns(cols)).collect()
+    }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -77,18 +109,76 @@ pub enum Alignment {
     Minus,
     Plus,
 }
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Where lines are placed horizontally within their row. Lines are placed on the left by default.
+pub enum HAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Where each section's content is placed vertically, within the rows on its side of the divider.
+/// Content is placed next to the divider by default. Edge places it at the top or bottom of the process instead,
+/// and Center splits the blank rows evenly before and after it, with the extra row after it if they can't be split evenly.
+pub enum VAlign {
+    #[default]
+    Divider,
+    Center,
+    Edge,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Which way rows are laid out. Rows are laid out left to right by default.
+/// Right to left rows start at the right edge, and their characters are printed in reverse order.
+pub enum TextDirection {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Which side gets the extra blank space when centered text can't be placed exactly in the middle.
+/// The extra space goes on the right by default.
+pub enum CenterBias {
+    Left,
+    #[default]
+    Right,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// What a process does with content that doesn't fit in a section.
+/// Error gives the content back in a FormatError, and is the default.
+/// Scroll keeps the content, and only shows as much of the section as fits. The window that's shown can be moved with DrawProcess::set_scroll().
+/// DropOldest keeps the newest content, removing the lines that were added first to make room for it, like a ring buffer.
+pub enum OverflowMode {
+    #[default]
+    Error,
+    Scroll,
+    DropOldest,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// What DrawProcess::add_to_section_front_with_mode() does with content that doesn't fit next to the divider.
+/// Reject adds none of it, giving all of it back in a FormatError, and is the default.
+/// Partial adds the lines closest to the divider that fit, and gives back the rest, like DrawProcess::add_to_section() does.
+/// Evict makes room by removing the lines on the outside of the section, which are the oldest ones, and only gives back what's too big for the whole section.
+pub enum PrependMode {
+    #[default]
+    Reject,
+    Partial,
+    Evict,
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Maximum {
+    #[default]
     None,
     X(usize, Alignment),
     Y(usize, Alignment),
 }
-impl Default for Maximum {
-    fn default() -> Self {
-        Maximum::None
-    }
-}
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
 /**
@@ -370,6 +460,30 @@ impl Grid {
         strategy.apply(self)
     }
     /**
+    Divides the grid at an absolute column into a left grid (start_x..at) and a right grid (at..end_x), each with the full height of the grid.
+    Either grid can be empty, if the column is at one of the grid's edges.
+    # Return value
+    Returns None if the column isn't inside the grid (from start_x to end_x, including both).
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() {
+    let grid = Frame::new(2, 0, 10, 5).next_frame();
+    let (left, right) = grid.split_x(6).unwrap();
+    assert_eq!(left, Grid {start_x: 2, start_y: 0, end_x: 6, end_y: 5});
+    assert_eq!(right, Grid {start_x: 6, start_y: 0, end_x: 10, end_y: 5});
+    assert!(grid.split_x(1).is_none());
+    assert!(grid.split_x(11).is_none());
+    # }
+    ```
+    */
+    pub fn split_x(&self, at: usize) -> Option<(Grid, Grid)> {
+        if at < self.start_x || at > self.end_x {
+            return None;
+        }
+        Some((Grid::new(self.start_x, self.start_y, at, self.end_y), Grid::new(at, self.start_y, self.end_x, self.end_y)))
+    }
+    /**
     Extends the grid in the either direction, either positive or negative, if the input is compatible
     (ie grids are next to each other and of similar dimensions)
     If the two grids are incompatible, it returns an error and gives the grid back. 
@@ -390,7 +504,6 @@ impl Grid {
     # }
     ```
     */
-    
     pub fn extend(&mut self, grid: Grid) -> Result<(), Grid> {
         if self.start_x == grid.start_x && self.end_x == grid.end_x {
             if self.end_y == grid.start_y {
@@ -415,6 +528,99 @@ impl Grid {
         Err(grid)
     }
     /**
+    Divides the grid into n grids of equal height, from top to bottom, each with the full width of the grid.
+    Rows that can't be split evenly go to the topmost grids. The grids are next to each other, and together they cover the entire grid.
+    Returns no grids if n is 0.
+    # Examples
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() {
+    let grid = Frame::new(0, 0, 10, 5).next_frame();
+    let rows = grid.into_rows(3);
+    assert_eq!(rows, vec![
+        Grid {start_x: 0, start_y: 0, end_x: 10, end_y: 2},
+        Grid {start_x: 0, start_y: 2, end_x: 10, end_y: 4},
+        Grid {start_x: 0, start_y: 4, end_x: 10, end_y: 5},
+    ]);
+    // The rows can be put back together into the original grid.
+    let mut rows = rows.into_iter();
+    let mut whole = rows.next().unwrap();
+    for row in rows {
+        assert!(whole.extend(row).is_ok());
+    }
+    assert_eq!(whole, grid);
+    assert!(grid.into_rows(0).is_empty());
+    # }
+    ```
+    */
+    pub fn into_rows(&self, n: usize) -> Vec<Grid> {
+        divide(self.start_y, self.end_y, n)
+            .map(|(start_y, end_y)| Grid::new(self.start_x, start_y, self.end_x, end_y))
+            .collect()
+    }
+    /**
+    Divides the grid into n grids of equal width, from left to right, each with the full height of the grid.
+    Columns that can't be split evenly go to the leftmost grids. Returns no grids if n is 0.
+    # Examples
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() {
+    let grid = Frame::new(0, 0, 5, 10).next_frame();
+    let columns = grid.into_columns(2);
+    assert_eq!(columns, vec![Grid {start_x: 0, start_y: 0, end_x: 3, end_y: 10}, Grid {start_x: 3, start_y: 0, end_x: 5, end_y: 10}]);
+    # }
+    ```
+    */
+    pub fn into_columns(&self, n: usize) -> Vec<Grid> {
+        divide(self.start_x, self.end_x, n)
+            .map(|(start_x, end_x)| Grid::new(start_x, self.start_y, end_x, self.end_y))
+            .collect()
+    }
+    /**
+    Divides the grid into grids from top to bottom, each getting a share of the height proportional to its weight.
+    Shares usually aren't whole numbers of rows, so they're rounded using the round mode.
+    Instead of rounding each grid's height on its own (which can leave a gap or an overlap), the boundaries between the grids are rounded,
+    so the grids always tile the entire grid exactly, whatever the round mode is. RoundMode::Nearest is recommended,
+    as it keeps every grid as close as possible to its exact share.
+    Negative weights count as 0. If all of the weights are 0, the grid is divided evenly, like into_rows().
+    # Examples
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() {
+    let grid = Frame::new(0, 0, 10, 10).next_frame();
+    let rows = grid.into_rows_weighted(&[1.0, 1.0, 1.0], RoundMode::Nearest);
+    assert_eq!(rows.iter().map(|row| row.end_y - row.start_y).collect::<Vec<_>>(), vec![3, 4, 3]);
+    let rows = grid.into_rows_weighted(&[1.0, 1.0, 1.0], RoundMode::Floor);
+    assert_eq!(rows.iter().map(|row| row.end_y - row.start_y).collect::<Vec<_>>(), vec![3, 3, 4]);
+    let rows = grid.into_rows_weighted(&[1.0, 1.0, 1.0], RoundMode::Ceil);
+    assert_eq!(rows.iter().map(|row| row.end_y - row.start_y).collect::<Vec<_>>(), vec![4, 3, 3]);
+    // Whatever the round mode is, the rows can be put back together into the original grid.
+    for mode in [RoundMode::Floor, RoundMode::Ceil, RoundMode::Nearest].iter() {
+        let mut rows = grid.into_rows_weighted(&[0.3, 0.25, 0.45], *mode).into_iter();
+        let mut whole = rows.next().unwrap();
+        for row in rows {
+            assert!(whole.extend(row).is_ok());
+        }
+        assert_eq!(whole, grid);
+    }
+    # }
+    ```
+    */
+    pub fn into_rows_weighted(&self, weights: &[f32], mode: RoundMode) -> Vec<Grid> {
+        divide_weighted(self.start_y, self.end_y, weights, mode)
+            .into_iter()
+            .map(|(start_y, end_y)| Grid::new(self.start_x, start_y, self.end_x, end_y))
+            .collect()
+    }
+    /// Divides the grid into grids from left to right, each getting a share of the width proportional to its weight.
+    /// The columns always tile the entire grid exactly. For details, see into_rows_weighted().
+    pub fn into_columns_weighted(&self, weights: &[f32], mode: RoundMode) -> Vec<Grid> {
+        divide_weighted(self.start_x, self.end_x, weights, mode)
+            .into_iter()
+            .map(|(start_x, end_x)| Grid::new(start_x, self.start_y, end_x, self.end_y))
+            .collect()
+    }
+    /**
     Converts the grid into a DrawProcess. The draw process can then be used to draw onto the terminal.
     # Examples
     ``` rust
@@ -432,16 +638,324 @@ impl Grid {
     pub fn into_process(self, strategy: DividerStrategy) -> DrawProcess {
         DrawProcess::new(self, strategy)
     }
+    /**
+    Converts the grid into a DrawProcess that places its lines using the inputted horizontal alignment.
+    This is the same as calling into_process() and then setting the alignment.
+    # Examples
+    ``` rust
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # use grid_ui::grid::*;
+    # fn main() -> Result<(), ()>{
+    let mut grid = Frame::new(0, 0, 10, 1).next_frame();
+    let mut process = grid.into_process_aligned(DividerStrategy::End, HAlign::Right);
+    process.add_to_section("42".to_string(), &mut Truncate, Alignment::Minus);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("        42\n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn into_process_aligned(self, strategy: DividerStrategy, h_align: HAlign) -> DrawProcess {
+        let mut process = DrawProcess::new(self, strategy);
+        process.set_h_align(h_align);
+        process
+    }
+    /**
+    Gets the smallest grid that contains both this grid and the other one. Unlike extend(), the grids don't need to be next to each other,
+    so the result can cover space that's in neither of them.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    let a = Frame::new(0, 0, 5, 2).next_frame();
+    let b = Frame::new(3, 4, 8, 6).next_frame();
+    assert_eq!(a.union(&b), Grid {start_x: 0, start_y: 0, end_x: 8, end_y: 6});
+    ```
+    */
+    pub fn union(&self, other: &Grid) -> Grid {
+        Grid::new(
+            self.start_x.min(other.start_x),
+            self.start_y.min(other.start_y),
+            self.end_x.max(other.end_x),
+            self.end_y.max(other.end_y),
+        )
+    }
+    /**
+    Checks whether a position is inside the grid. The end coordinates aren't part of the grid, so a position on them isn't inside it.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    let grid = Frame::new(2, 2, 5, 4).next_frame();
+    assert!(grid.contains(2, 3));
+    assert!(!grid.contains(5, 3));
+    assert!(!grid.contains(1, 2));
+    ```
+    */
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        (self.start_x..self.end_x).contains(&x) && (self.start_y..self.end_y).contains(&y)
+    }
+    /**
+    Checks whether the two grids share any cells. Grids that only touch along an edge don't, and neither do empty grids.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    let a = Frame::new(0, 0, 5, 2).next_frame();
+    let b = Frame::new(4, 1, 8, 6).next_frame();
+    let touching = Frame::new(5, 0, 8, 2).next_frame();
+    assert!(a.intersects(&b));
+    assert!(!a.intersects(&touching));
+    assert!(!touching.intersects(&a));
+    ```
+    */
+    pub fn intersects(&self, other: &Grid) -> bool {
+        self.start_x.max(other.start_x) < self.end_x.min(other.end_x) && self.start_y.max(other.start_y) < self.end_y.min(other.end_y)
+    }
+    /**
+    Gets how many cells are in the grid.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    assert_eq!(Frame::new(2, 2, 5, 4).next_frame().area(), 6);
+    ```
+    */
+    pub fn area(&self) -> usize {
+        (self.end_x - self.start_x) * (self.end_y - self.start_y)
+    }
 }
-
+/**
+A box drawn around the edge of a grid. Creating a border gives back the grid inside of it, which is one cell smaller on each side.
+The corners, the horizontal edges, and the vertical edges each have their own character, which are '+', '-', and '|' by default.
+# Example
+``` rust
+# use grid_ui::grid::*;
+# use grid_ui::out;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let grid = Frame::new(0, 0, 6, 3).next_frame();
+let (border, inner) = Border::new(grid).map_err(|_| ())?;
+assert_eq!(inner, Grid {start_x: 1, start_y: 1, end_x: 5, end_y: 2});
+let mut process = inner.into_process(DividerStrategy::Beginning);
+process.add_to_section("Hi".to_string(), &mut Truncate, Alignment::Plus);
+let mut buffer = out::StringBuffer::new(0, 0, 6, 3);
+border.print(&mut buffer, &mut ())?;
+process.print(&mut buffer, &mut ())?;
+assert_eq!(buffer.lines(), vec!["+----+".to_string(), "|Hi  |".to_string(), "+----+".to_string()]);
+assert!(Border::new(Frame::new(0, 0, 1, 5).next_frame()).is_err());
+# Ok(())
+# }
+```
+*/
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Border {
+    pub corner: char,
+    pub horizontal: char,
+    pub vertical: char,
+    grid: Grid,
+}
+impl Border {
+    /// Puts a border around the edge of a grid, giving back the border and the grid inside of it.
+    /// # Errors
+    /// If the grid is smaller than 2 by 2, there's no room for the border, so the grid is given back.
+    pub fn new(grid: Grid) -> Result<(Border, Grid), Grid> {
+        if grid.end_x - grid.start_x < 2 || grid.end_y - grid.start_y < 2 {
+            return Err(grid);
+        }
+        let inner = Grid::new(grid.start_x + 1, grid.start_y + 1, grid.end_x - 1, grid.end_y - 1);
+        let border = Border {
+            corner: '+',
+            horizontal: '-',
+            vertical: '|',
+            grid,
+        };
+        Ok((border, inner))
+    }
+    /// Gets the grid inside of the border.
+    pub fn inner(&self) -> Grid {
+        Grid::new(self.grid.start_x + 1, self.grid.start_y + 1, self.grid.end_x - 1, self.grid.end_y - 1)
+    }
+    /// Prints the border using a handler. Only the edge is printed, so the inside of the border is left alone.
+    /// # Errors
+    /// Returns an error if the handler returns an error.
+    pub fn print<H: Handler>(&self, handler: &mut H, out: &mut H::OutputDevice) -> Result<(), H::Error> {
+        let Grid { start_x, start_y, end_x, end_y } = self.grid;
+        let mut edge = String::new();
+        edge.push(self.corner);
+        edge.extend(std::iter::repeat(self.horizontal).take(end_x - start_x - 2));
+        edge.push(self.corner);
+        let vertical = self.vertical.to_string();
+        handler.handle(out, &Action::MoveTo(start_x, start_y))?;
+        handler.handle(out, &Action::Print(&edge))?;
+        for y in start_y + 1..end_y - 1 {
+            handler.handle(out, &Action::MoveTo(start_x, y))?;
+            handler.handle(out, &Action::Print(&vertical))?;
+            handler.handle(out, &Action::MoveTo(end_x - 1, y))?;
+            handler.handle(out, &Action::Print(&vertical))?;
+        }
+        handler.handle(out, &Action::MoveTo(start_x, end_y - 1))?;
+        handler.handle(out, &Action::Print(&edge))
+    }
+}
+#[doc(hidden)]
+/// Divides the range from start to end into n ranges that are as close to equal as possible, with the remainder going to the first ranges.
+fn divide(start: usize, end: usize, n: usize) -> impl Iterator<Item = (usize, usize)> {
+    let total = end - start;
+    (0..n).map(move |i| {
+        let position = |i: usize| start + total / n * i + (total % n).min(i);
+        (position(i), position(i + 1))
+    })
+}
+#[doc(hidden)]
+/// Divides the range from start to end into ranges proportional to the weights, rounding the boundaries between them.
+fn divide_weighted(start: usize, end: usize, weights: &[f32], mode: RoundMode) -> Vec<(usize, usize)> {
+    let total_weight: f32 = weights.iter().map(|weight| weight.max(0.0)).sum();
+    if total_weight <= 0.0 {
+        return divide(start, end, weights.len()).collect();
+    }
+    let total = (end - start) as f32;
+    let mut passed = 0.0;
+    let mut boundary = start;
+    weights
+        .iter()
+        .enumerate()
+        .map(|(i, weight)| {
+            passed += weight.max(0.0);
+            let last = boundary;
+            // The last boundary is the end, so rounding never leaves anything out.
+            boundary = if i + 1 == weights.len() { end } else { (start + mode.round(total * (passed / total_weight))).min(end) };
+            (last, boundary)
+        })
+        .collect()
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// How a fractional position is turned into a whole number of rows or columns.
+/// Nearest rounds halves up. Nearest is the default.
+pub enum RoundMode {
+    Floor,
+    Ceil,
+    #[default]
+    Nearest,
+}
+impl RoundMode {
+    /**
+    Rounds a value using the round mode. Negative values become 0.
+    # Example
+    ``` rust
+    # use grid_ui::grid::RoundMode;
+    assert_eq!(RoundMode::Floor.round(2.5), 2);
+    assert_eq!(RoundMode::Ceil.round(2.1), 3);
+    assert_eq!(RoundMode::Nearest.round(2.5), 3);
+    assert_eq!(RoundMode::Nearest.round(-1.0), 0);
+    ```
+    */
+    pub fn round(self, value: f32) -> usize {
+        let rounded = match self {
+            RoundMode::Floor => value.floor(),
+            RoundMode::Ceil => value.ceil(),
+            RoundMode::Nearest => value.round(),
+        };
+        rounded.max(0.0) as usize
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
 /// Where the divider will be placed. The divider is between two sides: A plus side and a minus side.
 /// Content can be added on the plus or minus side if there's space available.
+/// Ratio places the divider proportionally, with 0.0 at the top and 1.0 at the bottom, rounding it with the round mode.
+/// Ratios outside of that range are clamped to it.
 /// For examples of divider behavior, see docs for DrawProcess.
 pub enum DividerStrategy {
     Beginning,
     End,
     Halfway,
     Pos(usize),
+    Ratio(f32, RoundMode),
+}
+// Ratios are compared by their bits, so that every strategy is equal to itself.
+impl PartialEq for DividerStrategy {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (DividerStrategy::Beginning, DividerStrategy::Beginning) => true,
+            (DividerStrategy::End, DividerStrategy::End) => true,
+            (DividerStrategy::Halfway, DividerStrategy::Halfway) => true,
+            (DividerStrategy::Pos(a), DividerStrategy::Pos(b)) => a == b,
+            (DividerStrategy::Ratio(a, a_mode), DividerStrategy::Ratio(b, b_mode)) => a.to_bits() == b.to_bits() && a_mode == b_mode,
+            _ => false,
+        }
+    }
+}
+impl Eq for DividerStrategy {}
+impl Hash for DividerStrategy {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            DividerStrategy::Pos(v) => v.hash(state),
+            DividerStrategy::Ratio(r, mode) => {
+                r.to_bits().hash(state);
+                mode.hash(state);
+            }
+            _ => {}
+        }
+    }
+}
+impl DividerStrategy {
+    /**
+    Finds where the divider goes in a process of the inputted height, as an offset from the top of the process.
+    This is exactly where a DrawProcess created with this strategy puts its divider.
+    # Example
+    ``` rust
+    # use grid_ui::grid::*;
+    # fn main() {
+    assert_eq!(DividerStrategy::Beginning.resolve(10), 0);
+    assert_eq!(DividerStrategy::End.resolve(10), 10);
+    assert_eq!(DividerStrategy::Halfway.resolve(7), 3);
+    assert_eq!(DividerStrategy::Pos(4).resolve(10), 4);
+    assert_eq!(DividerStrategy::Ratio(0.3, RoundMode::Nearest).resolve(10), 3);
+    assert_eq!(DividerStrategy::Ratio(0.25, RoundMode::Floor).resolve(10), 2);
+    assert_eq!(DividerStrategy::Ratio(0.25, RoundMode::Ceil).resolve(10), 3);
+    assert_eq!(DividerStrategy::Ratio(1.5, RoundMode::Nearest).resolve(10), 10);
+    assert_eq!(DividerStrategy::Ratio(-0.5, RoundMode::Nearest).resolve(10), 0);
+    // With the same round mode, the divider lines up with the boundary of a weighted split of the same height.
+    let grid = Frame::new(0, 0, 5, 10).next_frame();
+    for mode in [RoundMode::Floor, RoundMode::Ceil, RoundMode::Nearest].iter() {
+        let rows = grid.into_rows_weighted(&[1.0, 2.0], *mode);
+        assert_eq!(DividerStrategy::Ratio(1.0 / 3.0, *mode).resolve(10), rows[0].end_y);
+    }
+    # }
+    ```
+    */
+    pub fn resolve(&self, height: usize) -> usize {
+        match self {
+            DividerStrategy::Beginning => 0,
+            DividerStrategy::End => height,
+            DividerStrategy::Halfway => height / 2,
+            DividerStrategy::Pos(v) => *v,
+            DividerStrategy::Ratio(r, mode) => mode.round(height as f32 * r.clamp(0.0, 1.0)).min(height),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Represents a problem with changing the shape of a grid or process.
+pub enum GeometryError {
+    /// The two areas don't share an edge of the same length.
+    NotAdjacent,
+    /// The change would move an edge past the other side of the area.
+    OutOfBounds,
+    /// The change would leave this many lines of content without any space.
+    Overflow(usize),
+}
+impl Display for GeometryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeometryError::NotAdjacent => write!(f, "The areas aren't adjacent"),
+            GeometryError::OutOfBounds => write!(f, "The edge can't be moved that far"),
+            GeometryError::Overflow(lines) => write!(f, "{} lines of content wouldn't fit", lines),
+        }
+    }
 }
+impl Error for GeometryError {}
@@ -1,6 +1,11 @@
 pub mod grid;
 pub mod out;
 pub mod process;
+pub mod screen;
 pub mod trim;
 #[cfg(feature = "crossterm")]
 pub mod crossterm;
+#[cfg(feature = "ratatui")]
+pub mod ratatui;
+#[cfg(feature = "testing")]
+pub mod testing;
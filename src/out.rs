@@ -1,14 +1,88 @@
+use std::{
+    io::{self, Write},
+    marker::PhantomData,
+};
+
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::grid::Frame;
+use crate::{
+    grid::{Frame, GeometryError, Grid},
+    process::DrawProcess,
+    trim::text_width,
+};
 
-/// Currently, an action is either printing a string or moving to a location.
-/// The first value is the x location, the second is the y location.
+/// Currently, an action is either printing a string, printing a character several times, printing a link, or moving to a location.
+/// For MoveTo, the first value is the x location, the second is the y location.
+/// MoveBy moves the cursor relative to where it is, by the first value horizontally and the second value vertically.
+/// Handlers that keep track of the cursor themselves stop it at the edges of their output.
+/// Repeat prints the character the given number of times. Handlers that can't do this efficiently can treat it as a Print.
+/// Hyperlink prints the text as a link to the uri. Only the text is visible, so only the text takes up space.
+/// Terminal handlers emit an OSC 8 link around the text; handlers that don't support links just print the text.
+/// BeginSyncUpdate and EndSyncUpdate surround a frame, so that terminals supporting synchronized output (DEC mode 2026)
+/// show it all at once. Handlers that don't support it ignore them.
+/// SetColor and SetBackground change the text and background colors of everything printed after them, until ResetColor.
+/// Handlers that don't support colors ignore all three.
+/// Clear blanks out the entire output, and ClearLine blanks out the row the cursor is on. Neither of them moves the cursor.
+/// Handlers that only collect text, like OutToString, treat Clear as starting over, and ignore ClearLine.
+/// DrawProcess::print() never sends these; they're for code that redraws the whole screen, to get rid of anything left over from the last frame.
+/// ``` rust
+/// # use grid_ui::out::*;
+/// # fn main() {
+/// let mut output = String::new();
+/// OutToString.safe_handle(&mut output, &Action::Repeat('-', 3));
+/// assert_eq!(output, "---\n".to_string());
+/// let mut buffer = StringBuffer::new(0, 0, 5, 2);
+/// buffer.safe_handle(&mut (), &Action::Print("ab"));
+/// buffer.safe_handle(&mut (), &Action::MoveBy(1, 5));
+/// buffer.safe_handle(&mut (), &Action::Print("c"));
+/// buffer.safe_handle(&mut (), &Action::MoveBy(-10, 0));
+/// buffer.safe_handle(&mut (), &Action::Print("d"));
+/// assert_eq!(buffer.clone().lines(), vec!["ab   ".to_string(), "d  c ".to_string()]);
+/// buffer.safe_handle(&mut (), &Action::ClearLine);
+/// assert_eq!(buffer.lines(), vec!["ab   ".to_string(), "     ".to_string()]);
+/// OutToString.safe_handle(&mut output, &Action::Clear);
+/// OutToString.safe_handle(&mut output, &Action::Print("new"));
+/// assert_eq!(output, "new\n".to_string());
+/// # }
+/// ```
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Action<'a> {
     Print(&'a str),
     MoveTo(usize, usize),
+    MoveBy(isize, isize),
+    Repeat(char, usize),
+    Hyperlink { uri: &'a str, text: &'a str },
+    BeginSyncUpdate,
+    EndSyncUpdate,
+    SetColor(Color),
+    SetBackground(Color),
+    ResetColor,
+    Clear,
+    ClearLine,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A color, which is either one of the 16 standard terminal colors, or an exact RGB color.
+/// How the standard colors look depends on the terminal.
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    Rgb(u8, u8, u8),
 }
 /**
 A handler is a structure that can convert actions into an output on an output device.
@@ -108,10 +182,354 @@ impl SafeHandler for OutToString {
                 out.push_str(s);
                 out.push('\n')
             }
-            Action::MoveTo(_, _) => {}
+            Action::Repeat(c, n) => {
+                out.extend(std::iter::repeat(*c).take(*n));
+                out.push('\n')
+            }
+            Action::Hyperlink { text, .. } => {
+                out.push_str(text);
+                out.push('\n')
+            }
+            Action::Clear => out.clear(),
+            Action::MoveTo(_, _)
+            | Action::MoveBy(_, _)
+            | Action::BeginSyncUpdate
+            | Action::EndSyncUpdate
+            | Action::SetColor(_)
+            | Action::SetBackground(_)
+            | Action::ResetColor
+            | Action::ClearLine => {}
+        }
+    }
+}
+/**
+A handler that writes text out to a string exactly like OutToString, but checks that everything it's told to do stays inside a grid.
+OutToString can't tell where text goes, so a process that's been placed in the wrong spot still prints without complaint.
+This handler returns GeometryError::OutOfBounds instead, so the mistake is caught where it happens.
+It keeps track of the cursor the way a terminal would, starting at the top left corner of the grid.
+Positions on the end coordinates of the grid are outside of it, like they are in Grid::contains(), but text may end right at the edge.
+# Errors
+Returns an error for a MoveTo or MoveBy that leaves the grid, or a Print, Hyperlink, or Repeat that runs past its right edge.
+Anything handled before that action stays in the string, and the cursor stays where it was.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let frame = grid::Frame::new(0, 0, 5, 2);
+let mut handler = CheckedOutToString::new(frame.next_frame());
+let mut process = frame.next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Hi".to_string(), &mut Truncate, grid::Alignment::Plus);
+let mut output: String = String::new();
+process.print(&mut handler, &mut output).map_err(|_| ())?;
+assert_eq!(output, "Hi   \n     \n".to_string());
+let outside = grid::Frame::new(0, 1, 5, 3).next_frame().into_process(grid::DividerStrategy::Beginning);
+assert_eq!(outside.print(&mut handler, &mut output), Err(grid::GeometryError::OutOfBounds));
+handler.handle(&mut output, &Action::MoveTo(3, 0)).map_err(|_| ())?;
+assert_eq!(handler.handle(&mut output, &Action::Print("abc")), Err(grid::GeometryError::OutOfBounds));
+assert_eq!(handler.handle(&mut output, &Action::MoveBy(0, 2)), Err(grid::GeometryError::OutOfBounds));
+handler.handle(&mut output, &Action::Print("ab")).map_err(|_| ())?;
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CheckedOutToString {
+    bounds: Grid,
+    position: (usize, usize),
+}
+impl CheckedOutToString {
+    /// Creates a handler that only accepts actions inside of the grid, with the cursor at its top left corner.
+    pub fn new(bounds: Grid) -> CheckedOutToString {
+        let position = (bounds.start_x, bounds.start_y);
+        CheckedOutToString { bounds, position }
+    }
+    #[doc(hidden)]
+    /// Finds where the cursor ends up after an action, or returns an error if the action leaves the grid.
+    fn moved(&self, input: &Action) -> Result<(usize, usize), GeometryError> {
+        let (x, y) = self.position;
+        let across = |width: usize| {
+            if x + width > self.bounds.end_x {
+                Err(GeometryError::OutOfBounds)
+            } else {
+                Ok((x + width, y))
+            }
+        };
+        match input {
+            Action::MoveTo(x, y) => self.inside(Some(*x), Some(*y)),
+            Action::MoveBy(dx, dy) => self.inside(x.checked_add_signed(*dx), y.checked_add_signed(*dy)),
+            Action::Print(text) | Action::Hyperlink { text, .. } => across(text_width(text)),
+            Action::Repeat(c, n) => across(n * text_width(c.encode_utf8(&mut [0; 4]))),
+            Action::BeginSyncUpdate
+            | Action::EndSyncUpdate
+            | Action::SetColor(_)
+            | Action::SetBackground(_)
+            | Action::ResetColor
+            | Action::Clear
+            | Action::ClearLine => Ok((x, y)),
+        }
+    }
+    #[doc(hidden)]
+    /// Returns the position if it's inside of the grid.
+    fn inside(&self, x: Option<usize>, y: Option<usize>) -> Result<(usize, usize), GeometryError> {
+        match (x, y) {
+            (Some(x), Some(y)) if self.bounds.contains(x, y) => Ok((x, y)),
+            _ => Err(GeometryError::OutOfBounds),
+        }
+    }
+}
+impl Handler for CheckedOutToString {
+    type OutputDevice = String;
+    type Error = GeometryError;
+    fn handle(&mut self, out: &mut String, input: &Action) -> Result<(), GeometryError> {
+        self.position = self.moved(input)?;
+        OutToString.safe_handle(out, input);
+        Ok(())
+    }
+}
+/**
+A handler that writes text out to a string like OutToString, but with colors, using ANSI escape sequences.
+Colors are reset before the newline at the end of a row, so they never carry over onto the next row.
+Without any color actions, the output is exactly the same as OutToString's.
+# Example
+``` rust
+# use grid_ui::out::*;
+# fn main() {
+let mut output = String::new();
+OutToAnsiString.safe_handle(&mut output, &Action::SetColor(Color::Green));
+OutToAnsiString.safe_handle(&mut output, &Action::SetBackground(Color::Rgb(0, 0, 64)));
+OutToAnsiString.safe_handle(&mut output, &Action::Print("ok"));
+OutToAnsiString.safe_handle(&mut output, &Action::ResetColor);
+OutToAnsiString.safe_handle(&mut output, &Action::Print("plain"));
+assert_eq!(output, "\x1b[32m\x1b[48;2;0;0;64mok\x1b[0m\nplain\n".to_string());
+# }
+```
+*/
+pub struct OutToAnsiString;
+impl SafeHandler for OutToAnsiString {
+    type OutputDevice = String;
+    fn safe_handle(&mut self, out: &mut String, input: &Action) {
+        match input {
+            Action::SetColor(color) => out.push_str(&ansi_color(*color, false)),
+            Action::SetBackground(color) => out.push_str(&ansi_color(*color, true)),
+            Action::ResetColor => {
+                if out.ends_with('\n') {
+                    out.insert_str(out.len() - 1, "\x1b[0m");
+                } else {
+                    out.push_str("\x1b[0m");
+                }
+            }
+            _ => OutToString.safe_handle(out, input),
+        }
+    }
+}
+#[doc(hidden)]
+/// Gets the ANSI escape sequence that sets the text or background color.
+fn ansi_color(color: Color, background: bool) -> String {
+    let code = match color {
+        Color::Rgb(r, g, b) => return format!("\x1b[{};2;{};{};{}m", if background { 48 } else { 38 }, r, g, b),
+        Color::Black => 30,
+        Color::Red => 31,
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::White => 37,
+        Color::BrightBlack => 90,
+        Color::BrightRed => 91,
+        Color::BrightGreen => 92,
+        Color::BrightYellow => 93,
+        Color::BrightBlue => 94,
+        Color::BrightMagenta => 95,
+        Color::BrightCyan => 96,
+        Color::BrightWhite => 97,
+    };
+    // Background colors have the same codes, moved up by 10.
+    format!("\x1b[{}m", if background { code + 10 } else { code })
+}
+/**
+A handler that writes directly to anything that implements Write, such as a file or stdout().lock().
+Moves and colors are written as ANSI escape sequences, and text is written as its bytes.
+Nothing is buffered by the handler itself.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), std::io::Error>{
+let mut process = grid::Frame::new(2, 1, 7, 3).next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Hi".to_string(), &mut Truncate, grid::Alignment::Plus);
+let mut output: Vec<u8> = Vec::new();
+process.print(&mut out::OutToWriter::new(), &mut output)?;
+assert_eq!(output, b"\x1b[2;3HHi   \x1b[3;3H     ".to_vec());
+# Ok(())
+# }
+```
+*/
+pub struct OutToWriter<W: Write> {
+    output: PhantomData<fn(&mut W)>,
+}
+impl<W: Write> OutToWriter<W> {
+    /// Creates a handler that writes to W.
+    pub fn new() -> OutToWriter<W> {
+        OutToWriter { output: PhantomData }
+    }
+}
+impl<W: Write> Default for OutToWriter<W> {
+    fn default() -> Self {
+        OutToWriter::new()
+    }
+}
+impl<W: Write> Handler for OutToWriter<W> {
+    type OutputDevice = W;
+    type Error = io::Error;
+    fn handle(&mut self, out: &mut W, input: &Action) -> Result<(), io::Error> {
+        match input {
+            Action::Print(s) => out.write_all(s.as_bytes()),
+            // ANSI positions start at 1, not 0.
+            Action::MoveTo(x, y) => write!(out, "\x1b[{};{}H", y + 1, x + 1),
+            Action::MoveBy(dx, dy) => {
+                // Terminals treat a move of 0 as a move of 1, so those are skipped.
+                if *dx != 0 {
+                    write!(out, "\x1b[{}{}", dx.unsigned_abs(), if *dx > 0 { 'C' } else { 'D' })?;
+                }
+                if *dy != 0 {
+                    write!(out, "\x1b[{}{}", dy.unsigned_abs(), if *dy > 0 { 'B' } else { 'A' })?;
+                }
+                Ok(())
+            }
+            Action::Repeat(c, n) => out.write_all(std::iter::repeat(*c).take(*n).collect::<String>().as_bytes()),
+            Action::Hyperlink { uri, text } => write!(out, "\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", uri, text),
+            Action::BeginSyncUpdate => out.write_all(b"\x1b[?2026h"),
+            Action::EndSyncUpdate => out.write_all(b"\x1b[?2026l"),
+            Action::SetColor(color) => out.write_all(ansi_color(*color, false).as_bytes()),
+            Action::SetBackground(color) => out.write_all(ansi_color(*color, true).as_bytes()),
+            Action::ResetColor => out.write_all(b"\x1b[0m"),
+            Action::Clear => out.write_all(b"\x1b[2J"),
+            Action::ClearLine => out.write_all(b"\x1b[2K"),
         }
     }
 }
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// An action that owns its text, so that it can be kept after the action it was made from is gone.
+/// Every variant matches the Action variant with the same name.
+/// With the serde feature, both can be serialized. An Action can only be deserialized by borrowing its text from the input,
+/// so a RecordedAction is the one to use for saving actions and loading them again later.
+pub enum RecordedAction {
+    Print(String),
+    MoveTo(usize, usize),
+    MoveBy(isize, isize),
+    Repeat(char, usize),
+    Hyperlink { uri: String, text: String },
+    BeginSyncUpdate,
+    EndSyncUpdate,
+    SetColor(Color),
+    SetBackground(Color),
+    ResetColor,
+    Clear,
+    ClearLine,
+}
+impl RecordedAction {
+    /// Borrows the recorded action as an action, so that it can be sent to a handler again.
+    pub fn as_action(&self) -> Action<'_> {
+        match self {
+            RecordedAction::Print(text) => Action::Print(text),
+            RecordedAction::MoveTo(x, y) => Action::MoveTo(*x, *y),
+            RecordedAction::MoveBy(dx, dy) => Action::MoveBy(*dx, *dy),
+            RecordedAction::Repeat(c, n) => Action::Repeat(*c, *n),
+            RecordedAction::Hyperlink { uri, text } => Action::Hyperlink { uri, text },
+            RecordedAction::BeginSyncUpdate => Action::BeginSyncUpdate,
+            RecordedAction::EndSyncUpdate => Action::EndSyncUpdate,
+            RecordedAction::SetColor(color) => Action::SetColor(*color),
+            RecordedAction::SetBackground(color) => Action::SetBackground(*color),
+            RecordedAction::ResetColor => Action::ResetColor,
+            RecordedAction::Clear => Action::Clear,
+            RecordedAction::ClearLine => Action::ClearLine,
+        }
+    }
+}
+impl From<&Action<'_>> for RecordedAction {
+    fn from(action: &Action<'_>) -> Self {
+        match action {
+            Action::Print(text) => RecordedAction::Print(text.to_string()),
+            Action::MoveTo(x, y) => RecordedAction::MoveTo(*x, *y),
+            Action::MoveBy(dx, dy) => RecordedAction::MoveBy(*dx, *dy),
+            Action::Repeat(c, n) => RecordedAction::Repeat(*c, *n),
+            Action::Hyperlink { uri, text } => RecordedAction::Hyperlink {
+                uri: uri.to_string(),
+                text: text.to_string(),
+            },
+            Action::BeginSyncUpdate => RecordedAction::BeginSyncUpdate,
+            Action::EndSyncUpdate => RecordedAction::EndSyncUpdate,
+            Action::SetColor(color) => RecordedAction::SetColor(*color),
+            Action::SetBackground(color) => RecordedAction::SetBackground(*color),
+            Action::ResetColor => RecordedAction::ResetColor,
+            Action::Clear => RecordedAction::Clear,
+            Action::ClearLine => RecordedAction::ClearLine,
+        }
+    }
+}
+/**
+A handler that records every action it's given, in order, so that the exact actions can be checked in tests.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::{RecordedAction, RecordingHandler};
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let mut grid = grid::Frame::new(2, 3, 6, 4).next_frame();
+let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Hi".to_string(), &mut Truncate, grid::Alignment::Plus);
+let mut actions = Vec::new();
+process.print(&mut RecordingHandler, &mut actions)?;
+assert_eq!(actions, vec![RecordedAction::MoveTo(2, 3), RecordedAction::Print("Hi  ".to_string())]);
+# Ok(())
+# }
+```
+*/
+pub struct RecordingHandler;
+impl SafeHandler for RecordingHandler {
+    type OutputDevice = Vec<RecordedAction>;
+    fn safe_handle(&mut self, out: &mut Vec<RecordedAction>, input: &Action) {
+        out.push(input.into());
+    }
+}
+/**
+Sends recorded actions to a handler, in order, as if a process was being printed with it.
+With the json feature, recorded actions can be saved and loaded again, so a render can be replayed somewhere else.
+# Errors
+Returns the first error that the handler returns, without sending the rest of the actions.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::{self, RecordedAction, RecordingHandler};
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let mut grid = grid::Frame::new(0, 0, 4, 2).next_frame();
+let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Hi".to_string(), &mut Truncate, grid::Alignment::Plus);
+let mut actions: Vec<RecordedAction> = Vec::new();
+process.print(&mut RecordingHandler, &mut actions)?;
+# #[cfg(feature = "json")] {
+let saved = serde_json::to_string(&actions).map_err(|_| ())?;
+actions = serde_json::from_str(&saved).map_err(|_| ())?;
+# }
+let mut output = String::new();
+out::replay(&actions, &mut out::OutToString, &mut output)?;
+assert_eq!(output, "Hi  \n    \n".to_string());
+# Ok(())
+# }
+```
+*/
+pub fn replay<H: Handler>(actions: &[RecordedAction], handler: &mut H, out: &mut H::OutputDevice) -> Result<(), H::Error> {
+    for action in actions {
+        handler.handle(out, &action.as_action())?;
+    }
+    Ok(())
+}
 impl<H: SafeHandler> Handler for H {
     type OutputDevice = H::OutputDevice;
     type Error = ();
@@ -150,6 +568,17 @@ assert_eq!(vec!["Some stuff".to_string()], output.lines());
 # Ok(())
 # }
 ```
+Printing moves the cursor past the text, one column for each grapheme
+``` rust
+# use grid_ui::out::*;
+let mut buffer = StringBuffer::new(0, 0, 6, 1);
+buffer.safe_handle(&mut (), &Action::MoveTo(0, 0));
+buffer.safe_handle(&mut (), &Action::Print("éa"));
+buffer.safe_handle(&mut (), &Action::Print("b"));
+buffer.safe_handle(&mut (), &Action::Repeat('-', 2));
+buffer.safe_handle(&mut (), &Action::Print("!"));
+assert_eq!(buffer.lines(), vec!["éab--!".to_string()]);
+```
 Panicking with ignore
 ``` should_panic
 # use grid_ui::grid;
@@ -227,15 +656,554 @@ impl SafeHandler for StringBuffer {
 
     fn safe_handle(&mut self, _: &mut (), input: &Action) {
         match input {
-            Action::Print(v) => {
-                for (i, line) in v.grapheme_indices(true) {
+            Action::Print(v) | Action::Hyperlink { text: v, .. } => {
+                for (i, line) in v.graphemes(true).enumerate() {
                     self.contents[self.current_y][self.current_x + i] = line.to_string();
                 }
+                self.current_x += v.graphemes(true).count();
+            }
+            Action::Repeat(c, n) => {
+                for i in 0..*n {
+                    self.contents[self.current_y][self.current_x + i] = c.to_string();
+                }
+                self.current_x += n;
             }
             Action::MoveTo(x, y) => {
                 self.current_x = *x - self.offset_x;
                 self.current_y = *y - self.offset_y;
             }
+            Action::MoveBy(dx, dy) => {
+                // The cursor stops at the edges of the buffer.
+                let width = self.contents.first().map_or(0, Vec::len);
+                self.current_x = self.current_x.saturating_add_signed(*dx).min(width);
+                self.current_y = self.current_y.saturating_add_signed(*dy).min(self.contents.len().saturating_sub(1));
+            }
+            Action::Clear => {
+                for cell in self.contents.iter_mut().flatten() {
+                    *cell = " ".to_string();
+                }
+            }
+            Action::ClearLine => {
+                for cell in self.contents.get_mut(self.current_y).into_iter().flatten() {
+                    *cell = " ".to_string();
+                }
+            }
+            Action::BeginSyncUpdate | Action::EndSyncUpdate | Action::SetColor(_) | Action::SetBackground(_) | Action::ResetColor => {}
+        }
+    }
+}
+/**
+A handler that composes text into a string buffer, treating it as rows of text separated by newlines.
+Unlike OutToString, it pays attention to locations, and only overwrites the characters that are printed.
+This means that several processes can be printed into the same buffer, in any order, without clearing it.
+The buffer can start out with the text of an existing string (see with_text()), and is turned back into a string with text().
+If something is printed past the end of the buffer, rows are added, each filled with width fill characters.
+If something is printed past the end of a row, the row is extended with fill characters.
+Locations are counted in columns, like the process counts them (see trim::Truncate). The rows are kept apart until they're read, so each action only touches the row it's printed on.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let frame = grid::Frame::new(0, 0, 10, 2);
+let mut left = frame.next_frame();
+let right = left.split(&grid::SplitStrategy::new().max_x(5, grid::Alignment::Plus)).ok_or(())?;
+let mut left_process = left.into_process(grid::DividerStrategy::Beginning);
+let mut right_process = right.into_process(grid::DividerStrategy::Beginning);
+left_process.add_to_section("Some".to_string(), &mut Truncate, grid::Alignment::Plus);
+right_process.add_to_section("stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+let mut handler = OutToStringBuffer::new(10).with_fill('.');
+right_process.print(&mut handler, &mut ())?;
+assert_eq!(handler.text(), ".....stuff\n.....     \n".to_string());
+left_process.print(&mut handler, &mut ())?;
+assert_eq!(handler.text(), "Some stuff\n          \n".to_string());
+// Printing into an existing string only changes the characters that are printed.
+let mut handler = OutToStringBuffer::new(10).with_text("# header #\n");
+left_process.print(&mut handler, &mut ())?;
+assert_eq!(handler.text(), "Some der #\n          \n".to_string());
+// With the unicode-width feature, wide characters take up two columns, so the text after them starts where the process puts it.
+# #[cfg(feature = "unicode-width")] {
+let mut left = grid::Frame::new(0, 0, 6, 1).next_frame();
+let right = left.split(&grid::SplitStrategy::new().max_x(2, grid::Alignment::Plus)).ok_or(())?;
+let mut left_process = left.into_process(grid::DividerStrategy::Beginning);
+let mut right_process = right.into_process(grid::DividerStrategy::Beginning);
+left_process.add_to_section("日本".to_string(), &mut Truncate, grid::Alignment::Plus);
+right_process.add_to_section("ab".to_string(), &mut Truncate, grid::Alignment::Plus);
+let mut handler = OutToStringBuffer::new(6);
+left_process.print(&mut handler, &mut ())?;
+right_process.print(&mut handler, &mut ())?;
+assert_eq!(handler.text(), "日本ab\n".to_string());
+# }
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OutToStringBuffer {
+    width: usize,
+    fill: char,
+    rows: Vec<Vec<String>>,
+    current_x: usize,
+    current_y: usize,
+}
+impl OutToStringBuffer {
+    /// Creates a new handler with an empty buffer, which adds rows of the inputted width when it needs to. The rows are filled with spaces by default.
+    pub fn new(width: usize) -> OutToStringBuffer {
+        OutToStringBuffer {
+            width,
+            fill: ' ',
+            rows: Vec::new(),
+            current_x: 0,
+            current_y: 0,
+        }
+    }
+    /// Sets the character used to fill new space.
+    pub fn with_fill(mut self, fill: char) -> Self {
+        self.fill = fill;
+        self
+    }
+    /// Replaces the buffer with the text of an existing string, so that printing overwrites parts of it.
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.rows = text.lines().map(|row| columns(row).collect()).collect();
+        self
+    }
+    /// Gets the buffer as a string, with a newline after every row.
+    pub fn text(&self) -> String {
+        let mut res = String::new();
+        for row in &self.rows {
+            res.extend(row.iter().map(String::as_str));
+            res.push('\n');
+        }
+        res
+    }
+    #[doc(hidden)]
+    /// Overwrites the text at the current location, moving past it.
+    fn write(&mut self, text: &str) {
+        while self.rows.len() <= self.current_y {
+            self.rows.push(vec![self.fill.to_string(); self.width]);
+        }
+        let row = &mut self.rows[self.current_y];
+        let cells = columns(text).collect::<Vec<_>>();
+        let len = cells.len();
+        if row.len() < self.current_x + len {
+            row.resize(self.current_x + len, self.fill.to_string());
+        }
+        for (cell, text) in row[self.current_x..].iter_mut().zip(cells) {
+            *cell = text;
+        }
+        self.current_x += len;
+    }
+    #[doc(hidden)]
+    /// Fills a row with the fill character, or every row if there isn't one. Rows that the buffer doesn't have are left alone.
+    fn clear(&mut self, only: Option<usize>) {
+        for (y, row) in self.rows.iter_mut().enumerate() {
+            if only.map_or(true, |only| only == y) {
+                for cell in row.iter_mut() {
+                    *cell = self.fill.to_string();
+                }
+            }
+        }
+    }
+}
+impl SafeHandler for OutToStringBuffer {
+    type OutputDevice = ();
+    fn safe_handle(&mut self, _: &mut (), input: &Action) {
+        match input {
+            Action::Print(s) | Action::Hyperlink { text: s, .. } => self.write(s),
+            Action::Repeat(c, n) => self.write(&std::iter::repeat(*c).take(*n).collect::<String>()),
+            Action::MoveTo(x, y) => {
+                self.current_x = *x;
+                self.current_y = *y;
+            }
+            Action::MoveBy(dx, dy) => {
+                // The buffer grows when it needs to, so the cursor only stops at the top and left edges.
+                self.current_x = self.current_x.saturating_add_signed(*dx);
+                self.current_y = self.current_y.saturating_add_signed(*dy);
+            }
+            Action::Clear => self.clear(None),
+            Action::ClearLine => self.clear(Some(self.current_y)),
+            Action::BeginSyncUpdate | Action::EndSyncUpdate | Action::SetColor(_) | Action::SetBackground(_) | Action::ResetColor => {}
+        }
+    }
+}
+#[doc(hidden)]
+/// Splits text into the columns it takes up. A wide grapheme is kept in its first column, and the other columns it covers are empty.
+fn columns(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.graphemes(true)
+        .flat_map(|grapheme| std::iter::once(grapheme.to_string()).chain(std::iter::repeat(String::new()).take(text_width(grapheme).max(1) - 1)))
+}
+/**
+A handler that draws text into rows of cells, with their colors and links, so that it can be turned into HTML with html().
+Like OutToStringBuffer, it pays attention to locations, and rows are added and extended with blank space when something is printed past them.
+The HTML is a single pre block, so spaces and rows are kept as they are. Every run of cells with the same colors and link
+is put in a span with inline styles (or a link), and special characters are escaped.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let mut process = grid::Frame::new(0, 0, 5, 2).next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("a<b".to_string(), &mut Truncate, grid::Alignment::Plus);
+process.add_colored_to_section("Hi".to_string(), Color::Red, &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+let mut handler = OutToHtml::new();
+process.print(&mut handler, &mut ())?;
+assert_eq!(handler.html(), "<pre>a&lt;b  \n<span style=\"color:#800000\">Hi   </span>\n</pre>".to_string());
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct OutToHtml {
+    rows: Vec<Vec<HtmlCell>>,
+    current: HtmlCell,
+    current_x: usize,
+    current_y: usize,
+}
+#[doc(hidden)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A single cell of an OutToHtml, with the style it was printed with.
+struct HtmlCell {
+    text: String,
+    color: Option<Color>,
+    background: Option<Color>,
+    link: Option<String>,
+}
+impl Default for HtmlCell {
+    fn default() -> Self {
+        HtmlCell {
+            text: " ".to_string(),
+            color: None,
+            background: None,
+            link: None,
+        }
+    }
+}
+impl HtmlCell {
+    #[doc(hidden)]
+    /// Checks whether two cells can go in the same span.
+    fn same_style(&self, other: &HtmlCell) -> bool {
+        self.color == other.color && self.background == other.background && self.link == other.link
+    }
+}
+impl OutToHtml {
+    /// Creates a new handler, with nothing drawn yet.
+    pub fn new() -> OutToHtml {
+        OutToHtml::default()
+    }
+    /// Gets everything drawn so far as text, one string per row, without any colors or links.
+    pub fn lines(&self) -> Vec<String> {
+        self.rows.iter().map(|row| row.iter().map(|cell| cell.text.as_str()).collect()).collect()
+    }
+    /// Gets everything drawn so far as a pre block of HTML.
+    pub fn html(&self) -> String {
+        let mut res = "<pre>".to_string();
+        for row in &self.rows {
+            let mut start = 0;
+            while start < row.len() {
+                let len = row[start..].iter().take_while(|cell| cell.same_style(&row[start])).count();
+                let text = row[start..start + len].iter().map(|cell| escape_html(&cell.text)).collect::<String>();
+                res.push_str(&html_span(&row[start], &text));
+                start += len;
+            }
+            res.push('\n');
+        }
+        res.push_str("</pre>");
+        res
+    }
+    #[doc(hidden)]
+    /// Puts text into the cells at the current location, with the current style, moving past it.
+    fn write(&mut self, text: &str, link: Option<&str>) {
+        while self.rows.len() <= self.current_y {
+            self.rows.push(Vec::new());
+        }
+        let row = &mut self.rows[self.current_y];
+        for grapheme in text.graphemes(true) {
+            // A wide grapheme is kept in its first cell, and the other cells it covers are empty.
+            let width = text_width(grapheme).max(1);
+            if row.len() < self.current_x + width {
+                row.resize(self.current_x + width, HtmlCell::default());
+            }
+            for (i, cell) in row[self.current_x..self.current_x + width].iter_mut().enumerate() {
+                *cell = HtmlCell {
+                    text: if i == 0 { grapheme.to_string() } else { String::new() },
+                    link: link.map(str::to_string),
+                    ..self.current.clone()
+                };
+            }
+            self.current_x += width;
+        }
+    }
+}
+impl SafeHandler for OutToHtml {
+    type OutputDevice = ();
+    fn safe_handle(&mut self, _: &mut (), input: &Action) {
+        match input {
+            Action::Print(s) => self.write(s, None),
+            Action::Hyperlink { uri, text } => self.write(text, Some(uri)),
+            Action::Repeat(c, n) => self.write(&std::iter::repeat(*c).take(*n).collect::<String>(), None),
+            Action::MoveTo(x, y) => {
+                self.current_x = *x;
+                self.current_y = *y;
+            }
+            Action::MoveBy(dx, dy) => {
+                // The rows grow when they need to, so the cursor only stops at the top and left edges.
+                self.current_x = self.current_x.saturating_add_signed(*dx);
+                self.current_y = self.current_y.saturating_add_signed(*dy);
+            }
+            Action::SetColor(color) => self.current.color = Some(*color),
+            Action::SetBackground(color) => self.current.background = Some(*color),
+            Action::ResetColor => {
+                self.current.color = None;
+                self.current.background = None;
+            }
+            Action::Clear => {
+                for cell in self.rows.iter_mut().flatten() {
+                    *cell = HtmlCell::default();
+                }
+            }
+            Action::ClearLine => {
+                for cell in self.rows.get_mut(self.current_y).into_iter().flatten() {
+                    *cell = HtmlCell::default();
+                }
+            }
+            Action::BeginSyncUpdate | Action::EndSyncUpdate => {}
+        }
+    }
+}
+#[doc(hidden)]
+/// Escapes the characters that mean something in HTML.
+fn escape_html(text: &str) -> String {
+    let mut res = String::new();
+    for c in text.chars() {
+        match c {
+            '&' => res.push_str("&amp;"),
+            '<' => res.push_str("&lt;"),
+            '>' => res.push_str("&gt;"),
+            '"' => res.push_str("&quot;"),
+            '\'' => res.push_str("&#39;"),
+            _ => res.push(c),
         }
     }
+    res
+}
+#[doc(hidden)]
+/// Wraps escaped text in a span with the cell's colors, and a link if the cell has one.
+fn html_span(cell: &HtmlCell, text: &str) -> String {
+    let mut styles = Vec::new();
+    if let Some(color) = cell.color {
+        styles.push(format!("color:{}", css_color(color)));
+    }
+    if let Some(color) = cell.background {
+        styles.push(format!("background-color:{}", css_color(color)));
+    }
+    let text = if styles.is_empty() {
+        text.to_string()
+    } else {
+        format!("<span style=\"{}\">{}</span>", styles.join(";"), text)
+    };
+    match &cell.link {
+        Some(uri) => format!("<a href=\"{}\">{}</a>", escape_html(uri), text),
+        None => text,
+    }
+}
+#[doc(hidden)]
+/// Gets the CSS color for a color, using the usual VGA colors for the standard ones.
+fn css_color(color: Color) -> String {
+    let (r, g, b) = match color {
+        Color::Black => (0, 0, 0),
+        Color::Red => (128, 0, 0),
+        Color::Green => (0, 128, 0),
+        Color::Yellow => (128, 128, 0),
+        Color::Blue => (0, 0, 128),
+        Color::Magenta => (128, 0, 128),
+        Color::Cyan => (0, 128, 128),
+        Color::White => (192, 192, 192),
+        Color::BrightBlack => (128, 128, 128),
+        Color::BrightRed => (255, 0, 0),
+        Color::BrightGreen => (0, 255, 0),
+        Color::BrightYellow => (255, 255, 0),
+        Color::BrightBlue => (0, 0, 255),
+        Color::BrightMagenta => (255, 0, 255),
+        Color::BrightCyan => (0, 255, 255),
+        Color::BrightWhite => (255, 255, 255),
+        Color::Rgb(r, g, b) => (r, g, b),
+    };
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+/**
+A handler that draws text as an SVG image, which is useful for screenshots in documentation, since it can be scaled without getting blurry.
+Every Print (or other text) becomes its own text element, placed at the cursor. Locations are measured in cells, which are turned into pixels
+with the cell width and height, and the text is stretched to exactly fill its cells, so it lines up no matter which monospace font is used.
+Every piece of text gets a rectangle behind it, filled with its background color, or with default_background if it doesn't have one.
+Things drawn later are drawn on top of things drawn earlier, so blank space printed over text covers it up, like it would in a terminal.
+The image is made big enough to fit everything that was drawn.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::*;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let mut process = grid::Frame::new(0, 0, 5, 2).next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Hi".to_string(), &mut Truncate, grid::Alignment::Plus);
+process.add_colored_to_section("There".to_string(), Color::Red, &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+let mut handler = OutToSvg::new(10, 20);
+process.print(&mut handler, &mut ())?;
+let svg = handler.svg();
+assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"50\" height=\"40\""));
+assert!(svg.contains("<text x=\"0\" y=\"0\" textLength=\"50\" lengthAdjust=\"spacingAndGlyphs\">Hi   </text>"));
+assert!(svg.contains("<text x=\"0\" y=\"20\" textLength=\"50\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#800000\">There</text>"));
+// Blank space printed over the first row hides the text that was there.
+handler.safe_handle(&mut (), &Action::MoveTo(0, 0));
+handler.safe_handle(&mut (), &Action::Repeat(' ', 5));
+let svg = handler.svg();
+let cover = svg.rfind("<rect x=\"0\" y=\"0\" width=\"50\" height=\"20\" fill=\"#ffffff\"/>").ok_or(())?;
+assert!(cover > svg.find(">Hi   </text>").ok_or(())?);
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OutToSvg {
+    /// How wide each cell is, in pixels.
+    pub cell_width: usize,
+    /// How tall each cell is, in pixels. This is also the size of the font.
+    pub cell_height: usize,
+    /// The background color of text that isn't given one. It's white to begin with.
+    pub default_background: Color,
+    elements: Vec<SvgElement>,
+    current_x: usize,
+    current_y: usize,
+    color: Option<Color>,
+    background: Option<Color>,
+}
+#[doc(hidden)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Something drawn by an OutToSvg, with the cells it covers.
+struct SvgElement {
+    x: usize,
+    y: usize,
+    len: usize,
+    markup: String,
+}
+impl OutToSvg {
+    /// Creates a new handler, with nothing drawn yet, that makes each cell cell_width by cell_height pixels.
+    pub fn new(cell_width: usize, cell_height: usize) -> OutToSvg {
+        OutToSvg {
+            cell_width,
+            cell_height,
+            default_background: Color::BrightWhite,
+            elements: Vec::new(),
+            current_x: 0,
+            current_y: 0,
+            color: None,
+            background: None,
+        }
+    }
+    /// Gets everything drawn so far as an SVG image.
+    pub fn svg(&self) -> String {
+        let columns = self.elements.iter().map(|element| element.x + element.len).max().unwrap_or(0);
+        let rows = self.elements.iter().map(|element| element.y + 1).max().unwrap_or(0);
+        let mut res = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"monospace\" font-size=\"{}\" \
+             dominant-baseline=\"text-before-edge\" xml:space=\"preserve\">",
+            columns * self.cell_width,
+            rows * self.cell_height,
+            self.cell_height
+        );
+        for element in &self.elements {
+            res.push_str(&element.markup);
+        }
+        res.push_str("</svg>");
+        res
+    }
+    #[doc(hidden)]
+    /// Draws text at the current location, with the current colors, moving past it.
+    fn write(&mut self, text: &str, link: Option<&str>) {
+        let len = text_width(text);
+        if len == 0 {
+            return;
+        }
+        let (x, y) = (self.current_x * self.cell_width, self.current_y * self.cell_height);
+        // The rectangle covers up anything that was drawn in these cells before.
+        let mut markup = format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+            x,
+            y,
+            len * self.cell_width,
+            self.cell_height,
+            css_color(self.background.unwrap_or(self.default_background))
+        );
+        let fill = self.color.map(|color| format!(" fill=\"{}\"", css_color(color))).unwrap_or_default();
+        let text = format!(
+            "<text x=\"{}\" y=\"{}\" textLength=\"{}\" lengthAdjust=\"spacingAndGlyphs\"{}>{}</text>",
+            x,
+            y,
+            len * self.cell_width,
+            fill,
+            escape_html(text)
+        );
+        match link {
+            Some(uri) => markup.push_str(&format!("<a href=\"{}\">{}</a>", escape_html(uri), text)),
+            None => markup.push_str(&text),
+        }
+        self.elements.push(SvgElement {
+            x: self.current_x,
+            y: self.current_y,
+            len,
+            markup,
+        });
+        self.current_x += len;
+    }
+}
+impl SafeHandler for OutToSvg {
+    type OutputDevice = ();
+    fn safe_handle(&mut self, _: &mut (), input: &Action) {
+        match input {
+            Action::Print(s) => self.write(s, None),
+            Action::Hyperlink { uri, text } => self.write(text, Some(uri)),
+            Action::Repeat(c, n) => self.write(&std::iter::repeat(*c).take(*n).collect::<String>(), None),
+            Action::MoveTo(x, y) => {
+                self.current_x = *x;
+                self.current_y = *y;
+            }
+            Action::MoveBy(dx, dy) => {
+                // The image grows when it needs to, so the cursor only stops at the top and left edges.
+                self.current_x = self.current_x.saturating_add_signed(*dx);
+                self.current_y = self.current_y.saturating_add_signed(*dy);
+            }
+            Action::SetColor(color) => self.color = Some(*color),
+            Action::SetBackground(color) => self.background = Some(*color),
+            Action::ResetColor => {
+                self.color = None;
+                self.background = None;
+            }
+            Action::Clear => self.elements.clear(),
+            Action::ClearLine => {
+                let y = self.current_y;
+                self.elements.retain(|element| element.y != y);
+            }
+            Action::BeginSyncUpdate | Action::EndSyncUpdate => {}
+        }
+    }
+}
+/**
+Gets the smallest grid that contains every one of the processes, which is useful for clearing or bordering a group of processes at once.
+Returns None if there aren't any processes.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out::bounding_grid;
+# fn main() -> Result<(), ()>{
+let top = grid::Frame::new(0, 0, 10, 2).next_frame().into_process(grid::DividerStrategy::Beginning);
+let corner = grid::Frame::new(0, 2, 3, 5).next_frame().into_process(grid::DividerStrategy::Beginning);
+assert_eq!(bounding_grid(&[&top, &corner]), Some(grid::Grid {start_x: 0, start_y: 0, end_x: 10, end_y: 5}));
+assert_eq!(bounding_grid(&[]), None);
+# Ok(())
+# }
+```
+*/
+pub fn bounding_grid(processes: &[&DrawProcess]) -> Option<Grid> {
+    processes.iter().map(|process| process.bounds()).reduce(|a, b| a.union(&b))
 }
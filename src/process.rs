@@ -1,12 +1,92 @@
-use crate::{grid::{Grid, Alignment, DividerStrategy}, out::{Action, Handler, SafeHandler}, trim::{TrimmedText, FormatError, TrimStrategy}};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    convert::Infallible,
+    hash::{Hash, Hasher},
+    io::{self, BufRead},
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{grid::{Grid, Alignment, CenterBias, DividerStrategy, GeometryError, HAlign, OverflowMode, PrependMode, TextDirection, VAlign}, out::{Action, Color, Handler, RecordedAction, SafeHandler}, trim::{cut, skip_columns, text_width, TrimmedText, FormatError, TrimStrategy}};
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum InternalFormatError {
     NoSpace(TrimmedText),
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What a process displays on a single row.
+enum Row<'a> {
+    Blank,
+    /// A blank row that's filled with a different character.
+    Shadow(char),
+    /// A line of content, along with where it's stored.
+    Content(Alignment, usize, &'a TrimmedText),
+    /// A line of one of the bands below the plus section.
+    Band(&'a TrimmedText),
+}
+#[derive(Debug, Clone, Default)]
+/// Maps the content of lines to the text printed for them, so that they aren't padded again every time they're printed.
+/// The cache only holds information derived from the rest of the process, so it's ignored when comparing and hashing processes.
+struct LineCache(Option<HashMap<String, String>>);
+impl PartialEq for LineCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl Eq for LineCache {}
+impl Hash for LineCache {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Everything that decides what a row looks like when it's printed.
+struct PrintedRow {
+    text: String,
+    color: Option<Color>,
+    background: Option<Color>,
+    link: Option<String>,
+}
+#[derive(Debug, Clone, Default)]
+/// The rows that print_diff() last printed, along with where they were printed.
+/// Like the line cache, this is ignored when comparing and hashing processes.
+struct Snapshot(Option<(Grid, Vec<PrintedRow>)>);
+impl PartialEq for Snapshot {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl Eq for Snapshot {}
+impl Hash for Snapshot {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Identifies a block of content added with DrawProcess::add_block().
+pub struct BlockId(usize);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A block is a range of consecutive lines in a section, which are added and removed together.
+/// Blocks with a link are printed as hyperlinks, and blocks with a color are printed in that color.
+struct Block {
+    id: BlockId,
+    section: Alignment,
+    start: usize,
+    len: usize,
+    link: Option<String>,
+    color: Option<Color>,
+}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// One of the bands below the plus section, which starts at a row and goes down to the next band or the bottom of the process.
+/// Its lines are printed from the top of the band down.
+struct Band {
+    start: usize,
+    lines: Vec<TrimmedText>,
+}
 /// A structure that can display text inside a grid.  
 /// Cloning chunk processes is bad practice! Use it only if you have to.  
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DrawProcess {
     start_x: usize,
@@ -17,6 +97,33 @@ pub struct DrawProcess {
     minus: Vec<TrimmedText>,
     plus: Vec<TrimmedText>,
     example_str: String,
+    fill_char: char,
+    fill_pattern: String,
+    h_align: HAlign,
+    v_align: VAlign,
+    direction: TextDirection,
+    center_bias: CenterBias,
+    hidden_minus: bool,
+    hidden_plus: bool,
+    collapse_hidden: bool,
+    collapse_blanks: bool,
+    divider_shadow: Option<char>,
+    writes: Vec<(usize, usize, String)>,
+    sync_updates: bool,
+    zebra: Option<(Color, Color)>,
+    gutter: usize,
+    cursor_anchor: Option<(Alignment, usize, usize)>,
+    overflow: OverflowMode,
+    scroll_minus: usize,
+    scroll_plus: usize,
+    blocks: Vec<Block>,
+    next_block: usize,
+    bands: Vec<Band>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    line_cache: LineCache,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    printed: Snapshot,
+    batching: usize,
 }
 impl DrawProcess {
     #[doc(hidden)]
@@ -27,15 +134,35 @@ impl DrawProcess {
             start_y: val.start_y,
             end_x: val.end_x,
             end_y: val.end_y,
-            divider: match strategy {
-                DividerStrategy::Beginning => 0,
-                DividerStrategy::End => val.end_y - val.start_y,
-                DividerStrategy::Halfway => (val.end_y - val.start_y) / 2,
-                DividerStrategy::Pos(v) => v,
-            },
+            divider: strategy.resolve(val.end_y - val.start_y),
             minus: Vec::new(),
             plus: Vec::new(),
             example_str: " ".chars().cycle().take(val.end_x - val.start_x).collect(),
+            fill_char: ' ',
+            fill_pattern: " ".to_string(),
+            h_align: HAlign::Left,
+            v_align: VAlign::Divider,
+            direction: TextDirection::LeftToRight,
+            center_bias: CenterBias::Right,
+            hidden_minus: false,
+            hidden_plus: false,
+            collapse_hidden: false,
+            collapse_blanks: false,
+            divider_shadow: None,
+            writes: Vec::new(),
+            sync_updates: false,
+            zebra: None,
+            gutter: 0,
+            cursor_anchor: None,
+            overflow: OverflowMode::Error,
+            scroll_minus: 0,
+            scroll_plus: 0,
+            blocks: Vec::new(),
+            next_block: 0,
+            bands: Vec::new(),
+            line_cache: LineCache::default(),
+            printed: Snapshot::default(),
+            batching: 0,
         }
     }
     /// Gets the chunk's width - the number of characters that can be displayed on a line.
@@ -125,6 +252,310 @@ impl DrawProcess {
     pub fn end_y(&self) -> usize {
         self.end_y
     }
+    /**
+    Gets the character that blank space is filled with. This is what a blank row is made of,
+    so it's what "nothing" looks like when comparing or combining the output of processes.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 3, 1).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    assert_eq!(process.fill_char(), ' ');
+    assert_eq!(process.row_at(0), Some(std::iter::repeat(process.fill_char()).take(3).collect()));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn fill_char(&self) -> char {
+        self.fill_char
+    }
+    /**
+    Sets the character that blank space is filled with. It's a space by default.
+    Blank rows are made of it, and so is the space that horizontal alignment moves lines over by.
+    Padding added by trim strategies is part of the content, so it stays the same. The fill character is kept by clear().
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 4, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.set_fill_char('·');
+    process.add_to_section("Hi".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Hi  \n····\n".to_string(), output);
+    process.clear(grid::DividerStrategy::Beginning);
+    assert_eq!(process.row_at(0), Some("····".to_string()));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_fill_char(&mut self, c: char) {
+        self.set_fill_pattern(&c.to_string());
+    }
+    /**
+    Sets a pattern that blank space is filled with, like set_fill_char(), but with more than one character.
+    The pattern is repeated across each blank row, lined up with the left edge of the terminal (x = 0) instead of the process,
+    so processes next to each other with the same pattern line up without a seam. fill_char() gives the first character of the pattern.
+    An empty pattern is treated as a single space.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # fn main() -> Result<(), ()>{
+    let mut left = grid::Frame::new(0, 0, 8, 1).next_frame();
+    let right = left.split(&grid::SplitStrategy::new().max_x(3, grid::Alignment::Plus)).ok_or(())?;
+    let mut left = left.into_process(grid::DividerStrategy::Beginning);
+    let mut right = right.into_process(grid::DividerStrategy::Beginning);
+    left.set_fill_pattern("-=+");
+    right.set_fill_pattern("-=+");
+    assert_eq!(left.row_at(0), Some("-=+-=".to_string()));
+    assert_eq!(right.row_at(0), Some("+-=".to_string()));
+    assert_eq!(left.fill_char(), '-');
+    # Ok(())
+    # }
+    ```
+    The space around aligned lines is filled with the pattern too, and right to left rows keep it where a blank row would have it.
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 8, 1).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.set_fill_pattern("-=+");
+    process.set_h_align(grid::HAlign::Right);
+    process.add_to_section("ab".to_string(), &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+    assert_eq!(process.row_at(0), Some("-=+-=+ab".to_string()));
+    process.set_direction(grid::TextDirection::RightToLeft);
+    assert_eq!(process.row_at(0), Some("ba+-=+-=".to_string()));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_fill_pattern(&mut self, pattern: &str) {
+        self.fill_pattern = if pattern.is_empty() { " ".to_string() } else { pattern.to_string() };
+        self.fill_char = self.fill_pattern.chars().next().unwrap_or(' ');
+        self.rebuild_blank();
+        self.invalidate();
+    }
+    #[doc(hidden)]
+    /// Rebuilds the blank row out of the fill pattern, for the process's current position and width.
+    fn rebuild_blank(&mut self) {
+        let pattern = self.fill_pattern.chars().collect::<Vec<_>>();
+        self.example_str = (self.start_x..self.end_x).map(|x| pattern[x % pattern.len()]).collect();
+    }
+    #[doc(hidden)]
+    /// Gets the grid that the process takes up.
+    pub(crate) fn bounds(&self) -> Grid {
+        Grid {
+            start_x: self.start_x,
+            start_y: self.start_y,
+            end_x: self.end_x,
+            end_y: self.end_y,
+        }
+    }
+    /**
+    Creates a new, empty process in the same place as this one. This is the way to reuse a process's geometry, instead of cloning it.
+    Only the position, the size, the settings that decide how it's printed (horizontal alignment, center bias, divider shadow,
+    and fill pattern), and the overflow mode are copied. The new process has no content, and its divider is placed using the given strategy.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+    let mut first = grid.into_process_aligned(grid::DividerStrategy::Beginning, grid::HAlign::Right);
+    first.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let mut second = first.empty_like(grid::DividerStrategy::End);
+    assert_eq!((second.width(), second.height()), (first.width(), first.height()));
+    assert_eq!(second.to_plain_lines(), Vec::<String>::new());
+    second.add_to_section("Right".to_string(), &mut Truncate, grid::Alignment::Minus);
+    assert_eq!(second.row_at(2), Some("     Right".to_string()));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn empty_like(&self, divider: DividerStrategy) -> DrawProcess {
+        let mut res = DrawProcess::new(self.bounds(), divider);
+        res.h_align = self.h_align;
+        res.v_align = self.v_align;
+        res.direction = self.direction;
+        res.center_bias = self.center_bias;
+        res.divider_shadow = self.divider_shadow;
+        res.overflow = self.overflow;
+        res.bands = self.bands.iter().map(|band| Band { start: band.start, lines: Vec::new() }).collect();
+        res.set_fill_pattern(&self.fill_pattern);
+        res
+    }
+    /**
+    Gets the content of the process as plain text lines, in the order they're displayed.
+    The blank space added by trim strategies is removed, and blank rows that aren't part of the content are skipped,
+    as are hidden sections.
+    This is the representation you'd want when copying a process's text somewhere else.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 6).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Halfway);
+    process.add_to_section("Top".to_string(), &mut Truncate, grid::Alignment::Minus);
+    process.add_to_section("Above top".to_string(), &mut Truncate, grid::Alignment::Minus);
+    process.add_to_section("Bottom".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(process.to_plain_lines(), vec!["Above top".to_string(), "Top".to_string(), "Bottom".to_string()]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn to_plain_lines(&self) -> Vec<String> {
+        self.rows()
+            .into_iter()
+            .filter_map(|row| match row {
+                Row::Blank | Row::Shadow(_) => None,
+                Row::Content(_, _, line) | Row::Band(line) => Some(line.unpadded().to_string()),
+            })
+            .collect()
+    }
+    /**
+    Gets the content of the process as plain text, with each line separated by a newline.
+    See to_plain_lines() for details.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 6).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("More".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(process.to_plain_text(), "Some stuff\nMore".to_string());
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn to_plain_text(&self) -> String {
+        self.to_plain_lines().join("\n")
+    }
+    /**
+    Gets the text printed on a row, using an absolute y position.
+    Returns None if the row is outside of the process.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 5, 10, 8).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(process.row_at(5), Some("Some stuff".to_string()));
+    assert_eq!(process.row_at(6), Some("          ".to_string()));
+    assert_eq!(process.row_at(8), None);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn row_at(&self, y: usize) -> Option<String> {
+        let index = y.checked_sub(self.start_y)?;
+        self.rows().get(index).map(|row| self.row_text(index, row).into_owned())
+    }
+    /**
+    Gets the character printed at an absolute position, whether it's part of the content or blank space.
+    Returns None if the position is outside of the process, or if nothing is printed there
+    (which can happen with strategies like Ignore that don't pad short lines).
+    Positions are counted in characters, not terminal columns. Wide characters count as one position,
+    and combining characters count as positions of their own.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Ignore;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some".to_string(), &mut Ignore, grid::Alignment::Plus);
+    assert_eq!(process.char_at(1, 0), Some('o'));
+    assert_eq!(process.char_at(5, 0), None);
+    assert_eq!(process.char_at(5, 1), Some(' '));
+    assert_eq!(process.char_at(10, 1), None);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn char_at(&self, x: usize, y: usize) -> Option<char> {
+        if x < self.start_x || x >= self.end_x {
+            return None;
+        }
+        self.row_at(y)?.chars().nth(x - self.start_x)
+    }
+    /**
+    Renders the process into a matrix of characters, with exactly height() rows of exactly width() characters.
+    Short lines are filled out with blank space, and long lines are cut off at the edge of the process.
+    Each character takes up one position, so wide characters and combining characters will make a row look longer or shorter than it is.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Ignore;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 3, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("a".to_string(), &mut Ignore, grid::Alignment::Plus);
+    assert_eq!(process.to_matrix(), vec![vec!['a', ' ', ' '], vec![' ', ' ', ' ']]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn to_matrix(&self) -> Vec<Vec<char>> {
+        let width = self.width();
+        let mut rows = self.rows();
+        rows.resize(self.height(), Row::Blank);
+        rows.iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let mut line = self.row_text(i, row).chars().take(width).collect::<Vec<_>>();
+                // Fills the rest of the line with the same blank space a blank row would have there.
+                let filled = line.len();
+                line.extend(self.example_str.chars().skip(filled));
+                line
+            })
+            .collect()
+    }
+    /**
+    Checks the stored content for control characters, which would corrupt the terminal if they were printed.
+    This includes newlines, carriage returns, tabs, escape characters (which start ANSI escape sequences), and
+    every other character that char::is_control() rejects.
+    # Errors
+    Returns the section and index of each line containing a control character. Lines are indexed in the order they were added,
+    with the minus section's lines coming first.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Ignore;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Fine".to_string(), &mut Ignore, grid::Alignment::Plus);
+    assert_eq!(process.validate_content(), Ok(()));
+    process.add_to_section("Not\nfine".to_string(), &mut Ignore, grid::Alignment::Plus);
+    assert_eq!(process.validate_content(), Err(vec![(grid::Alignment::Plus, 1)]));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn validate_content(&self) -> Result<(), Vec<(Alignment, usize)>> {
+        let minus = self.minus.iter().enumerate().map(|(i, line)| (Alignment::Minus, i, line));
+        let plus = self.plus.iter().enumerate().map(|(i, line)| (Alignment::Plus, i, line));
+        let errors = minus
+            .chain(plus)
+            .filter(|(_, _, line)| line.0.chars().any(char::is_control))
+            .map(|(a, i, _)| (a, i))
+            .collect::<Vec<_>>();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
     #[doc(hidden)]
     /// Trims a string using a trim strategy.
     fn trim<T: TrimStrategy>(&self, text: T::Input, b: &mut T, a: Alignment) -> Vec<TrimmedText> {
@@ -197,6 +628,13 @@ impl DrawProcess {
     process.print(&mut out::OutToString, &mut output)?;
     assert_eq!("More stuff\nEven more!\n".to_string(), output);
     assert!(result[0].is_err());
+    // Each result lines up with the text it came from, even though the text is added from last to first.
+    assert_eq!(result[0].as_ref().err().and_then(|e| e.recovered()), Some(&"Some stuff".to_string()));
+    assert!(result[1].is_ok() && result[2].is_ok());
+    let mut process = grid::Frame::new(0, 0, 10, 1).next_frame().into_process(grid::DividerStrategy::End);
+    let result = process.add_to_section_lines(vec!["Some stuff".to_string(), "More stuff".to_string(), "Even more!".to_string()].into_iter(), &mut Ignore, grid::Alignment::Minus);
+    assert_eq!(result[1].as_ref().err().and_then(|e| e.recovered()), Some(&"More stuff".to_string()));
+    assert!(result[0].is_err() && result[2].is_ok());
     # Ok(())
     # }
     ```
@@ -207,20 +645,64 @@ impl DrawProcess {
         I: DoubleEndedIterator,
         I: Iterator<Item = T::Input>,
     {
-        if matches!(section, Alignment::Minus) {
-            let text = text.rev();
-            let mut res = text.map(|x| self.add_to_section(x, strategy, section)).collect::<Vec<_>>();
-            if matches!(section, Alignment::Minus) {
+        match section {
+            // The minus section grows upwards, so the text is added from last to first to keep it in order.
+            // The results are then put back in the order of the text.
+            Alignment::Minus => {
+                let mut res = text.rev().map(|x| self.add_to_section(x, strategy, section)).collect::<Vec<_>>();
                 res.reverse();
+                res
             }
-            res
-        } else {
-            let mut res = text.map(|x| self.add_to_section(x, strategy, section)).collect::<Vec<_>>();
-            if matches!(section, Alignment::Minus) {
-                res.reverse();
+            Alignment::Plus => text.map(|x| self.add_to_section(x, strategy, section)).collect(),
+        }
+    }
+    /**
+    Adds multi-line content to a section, like add_to_section_lines(), but only if all of it fits. Otherwise, nothing is added.
+    Everything is trimmed first, so the strategy is updated even if nothing is added, like it is by plan().
+    # Errors
+    If the content doesn't all fit, returns the index of the first piece of text that doesn't, the same one that
+    add_to_section_lines() would give the first error for. The text isn't given back, so keep a copy of it if it's needed again.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    let form = vec!["Name:".to_string(), "Email:".to_string(), "Phone:".to_string()];
+    assert_eq!(process.try_add_all_lines(form.into_iter(), &mut Truncate, grid::Alignment::Plus), Err(2));
+    assert!(process.to_plain_lines().is_empty());
+    let form = vec!["Name:".to_string(), "Email:".to_string()];
+    assert_eq!(process.try_add_all_lines(form.into_iter(), &mut Truncate, grid::Alignment::Plus), Ok(()));
+    assert_eq!(process.to_plain_lines(), vec!["Name:", "Email:"]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn try_add_all_lines<T, I>(&mut self, text: I, strategy: &mut T, section: Alignment) -> Result<(), usize>
+    where
+        T: TrimStrategy,
+        I: Iterator<Item = T::Input>,
+    {
+        let mut trimmed = text.map(|x| self.trim(x, strategy, section)).collect::<Vec<_>>();
+        // The minus section is filled from the last text to the first, like add_to_section_lines() does.
+        let order = match section {
+            Alignment::Minus => (0..trimmed.len()).rev().collect::<Vec<_>>(),
+            Alignment::Plus => (0..trimmed.len()).collect(),
+        };
+        let mut needed = 0;
+        for &i in &order {
+            needed += trimmed[i].len();
+            if needed > self.room(section) {
+                return Err(i);
             }
-            res
         }
+        for i in order {
+            for line in std::mem::take(&mut trimmed[i]) {
+                self.add_to_section_trimmed(line, section).expect("Space was checked beforehand");
+            }
+        }
+        Ok(())
     }
     /**
     Adds single-line content to the selection, using the inputted strategy inside the inputted alignment.
@@ -288,7 +770,45 @@ impl DrawProcess {
     ```
     */
     pub fn add_to_section<T: TrimStrategy>(&mut self, text: T::Input, strategy: &mut T, section: Alignment) -> Result<(), FormatError<T>> {
+        self.add_to_section_counted(text, strategy, section).map(|_| ())
+    }
+    /**
+    Adds content to a section, just like add_to_section(), and gives back how many lines it took up after it was trimmed.
+    This is how many lines were added, which is useful for strategies that can make several lines out of one piece of content, like WordWrap.
+    # Errors
+    This function returns an error if the content can't fit, just like add_to_section(). The lines that fit are still added.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::WordWrap;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 6, 4).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    let added = process.add_to_section_counted("Some words wrap".to_string(), &mut WordWrap, grid::Alignment::Plus).map_err(|_| ())?;
+    assert_eq!(added, 3);
+    assert_eq!(process.free_lines(grid::Alignment::Plus), 1);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn add_to_section_counted<T: TrimStrategy>(&mut self, text: T::Input, strategy: &mut T, section: Alignment) -> Result<usize, FormatError<T>> {
         let text = self.trim(text, strategy, section);
+        let count = text.len();
+        let extras = self.add_all_trimmed(text, section);
+        if extras.is_empty() {
+            Ok(count)
+        } else {
+            Err(FormatError::NoSpace(strategy.back(extras, self, section)))
+        }
+    }
+    #[doc(hidden)]
+    /// Adds trimmed lines to a section one after another, until one doesn't fit. Gives back the lines that weren't added.
+    fn add_all_trimmed(&mut self, mut text: Vec<TrimmedText>, section: Alignment) -> Vec<TrimmedText> {
+        // Lines past the capacity would push out the start of the same content, so only the older lines are dropped to make room.
+        let excess = match self.overflow {
+            OverflowMode::DropOldest => text.split_off(text.len().min(self.capacity(section))),
+            _ => Vec::new(),
+        };
         let mut i = text.into_iter();
         let error: InternalFormatError = loop {
             if let Some(val) = i.next() {
@@ -298,61 +818,1343 @@ impl DrawProcess {
                     break e;
                 }
             } else {
-                // If we successfully made it through, we're ok.
-                return Ok(());
+                // If we successfully made it through, only the excess is left over.
+                return excess;
             }
         };
         match error {
-            InternalFormatError::NoSpace(back) => {
-                // Adds the text that couldn't be formatted back onto the start and collects them all.
-                let extras = Some(back).into_iter().chain(i).collect::<Vec<_>>();
-                // Adds the error.
-                Err(FormatError::NoSpace(strategy.back(extras, &self, section)))
-            }
+            // Adds the text that couldn't be formatted back onto the start and collects them all.
+            InternalFormatError::NoSpace(back) => Some(back).into_iter().chain(i).chain(excess).collect(),
         }
     }
     /**
-    Clears the process, allowing it to be re-used. 
+    Adds content to a section, like add_to_section(), but puts it next to the divider instead of after the existing content.
+    The existing content is pushed outwards, towards the edge of the process, so the newest content is always closest to the divider.
+    In the plus section, that's above the older content; in the minus section, that's below it. Multi-line content still reads from top to bottom.
+    In the plus section, the newest content is printed first, so this is the way to make a list that shows the most recent items at the top.
+    If the section doesn't have room for all of the content, none of it is added. To add as much as fits, or to make room by removing
+    the oldest lines, use add_to_section_front_with_mode().
+    # Errors
+    This function returns an error, without adding anything, if the content doesn't entirely fit.
     # Example
     ``` rust
     # use grid_ui::grid;
     # use grid_ui::out;
-    # use grid_ui::trim::Ignore;
+    # use grid_ui::trim::Truncate;
     # fn main() -> Result<(), ()>{
-    let mut grid = grid::Frame::new(0, 0, 10, 1).next_frame(); // creates a grid with one line
+    let mut grid = grid::Frame::new(0, 0, 6, 5).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Pos(2));
+    for line in ["First", "Second"].iter() {
+        process.add_to_section_front(line.to_string(), &mut Truncate, grid::Alignment::Minus);
+        process.add_to_section_front(line.to_string(), &mut Truncate, grid::Alignment::Plus);
+    }
+    assert_eq!(process.to_plain_lines(), vec!["First", "Second", "Second", "First"]);
+    assert!(process.add_to_section_front("No room".to_string(), &mut Truncate, grid::Alignment::Minus).is_err());
+    assert_eq!(process.to_plain_lines(), vec!["First", "Second", "Second", "First"]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn add_to_section_front<T: TrimStrategy>(&mut self, text: T::Input, strategy: &mut T, section: Alignment) -> Result<(), FormatError<T>> {
+        self.add_to_section_front_with_mode(text, strategy, section, PrependMode::Reject)
+    }
+    /**
+    Adds content next to the divider, like add_to_section_front(), but adds as much of it as fits, like add_to_section() does.
+    The content is trimmed the same way, and the lines closest to the divider are added first, so in the plus section
+    the newest content is printed at the top. This is the same as add_to_section_front_with_mode() with PrependMode::Partial.
+    # Errors
+    This function returns a NoSpace error with the content that didn't fit, like add_to_section().
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::{Truncate, WordWrap};
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 6, 3).next_frame();
     let mut process = grid.into_process(grid::DividerStrategy::Beginning);
-    process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
-    assert!(process.add_to_section("No more".to_string(), &mut Ignore, grid::Alignment::Plus).is_err());
-    process.clear(grid::DividerStrategy::Beginning);
-    assert!(process.add_to_section("Some more".to_string(), &mut Ignore, grid::Alignment::Plus).is_ok());
+    process.prepend_to_section("Old".to_string(), &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+    process.prepend_to_section("New".to_string(), &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+    let error = process.prepend_to_section("Newest one".to_string(), &mut WordWrap, grid::Alignment::Plus).unwrap_err();
+    assert_eq!(error.recovered(), Some(&"one".to_string()));
+    assert_eq!(process.to_plain_lines(), vec!["Newest", "New", "Old"]);
     # Ok(())
     # }
     ```
     */
-    pub fn clear(&mut self, new_strategy: DividerStrategy) {
-        *self = DrawProcess::new(Grid {
-            start_x: self.start_x,
-            start_y: self.start_y,
-            end_x: self.end_x,
-            end_y: self.end_y
-        }, new_strategy);
-    } 
+    pub fn prepend_to_section<T: TrimStrategy>(&mut self, text: T::Input, strategy: &mut T, section: Alignment) -> Result<(), FormatError<T>> {
+        self.add_to_section_front_with_mode(text, strategy, section, PrependMode::Partial)
+    }
     /**
-    Gives up free space in the Y direction, producing a grid if there's free space to give up. 
-    Will take up to max_taken lines of space. If max_taken is set to None, it will take up to the divider line. 
-    Will leave at least min_left lines TOTAL (in either direction). Might leave some blank lines. 
-    Returns this space in a grid if there is any. If the process is already full, nothing will be returned. 
+    Adds content next to the divider, like add_to_section_front(), with the mode deciding what happens when the section is full:
+    PrependMode::Reject leaves it alone, PrependMode::Partial adds as much as fits (like prepend_to_section()),
+    and PrependMode::Evict removes the lines on the outside of the section, which are the oldest ones, until the new content fits.
+    Evicting keeps the section to the rows it has, whatever the process's OverflowMode is.
+    # Errors
+    With PrependMode::Reject, this function returns an error, without adding anything, if the content doesn't entirely fit.
+    With PrependMode::Partial, the lines closest to the divider that fit are added, and the rest are given back in the error.
+    With PrependMode::Evict, it only returns an error if the content is taller than the whole section. The lines closest to the divider are added,
+    and the rest are given back.
     # Example
     ``` rust
     # use grid_ui::grid;
-    # use grid_ui::out;
-    # use grid_ui::trim::Ignore;
+    # use grid_ui::trim::Truncate;
     # fn main() -> Result<(), ()>{
-    let mut grid = grid::Frame::new(0, 0, 10, 10).next_frame();
+    let mut grid = grid::Frame::new(0, 0, 6, 5).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Pos(2));
+    process.add_to_section_front("First".to_string(), &mut Truncate, grid::Alignment::Minus).map_err(|_| ())?;
+    process.add_to_section_front("Second".to_string(), &mut Truncate, grid::Alignment::Minus).map_err(|_| ())?;
+    // Evicting pushes "First" out of the top of the minus section.
+    process.add_to_section_front_with_mode("Third".to_string(), &mut Truncate, grid::Alignment::Minus, grid::PrependMode::Evict).map_err(|_| ())?;
+    assert_eq!(process.to_plain_lines(), vec!["Second", "Third"]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn add_to_section_front_with_mode<T: TrimStrategy>(
+        &mut self,
+        text: T::Input,
+        strategy: &mut T,
+        section: Alignment,
+        mode: PrependMode,
+    ) -> Result<(), FormatError<T>> {
+        let mut text = self.trim(text, strategy, section);
+        let room = match mode {
+            PrependMode::Reject | PrependMode::Partial => self.room(section),
+            PrependMode::Evict => self.capacity(section),
+        };
+        if matches!(mode, PrependMode::Reject) && text.len() > room {
+            return Err(FormatError::NoSpace(strategy.back(text, self, section)));
+        }
+        let extras = text.split_off(text.len().min(room));
+        for line in &text {
+            self.cache_line(line);
+        }
+        let count = text.len();
+        match section {
+            Alignment::Minus => self.minus.splice(0..0, text),
+            Alignment::Plus => self.plus.splice(0..0, text),
+        };
+        self.lines_inserted(section, 0, count);
+        self.drop_outermost(section, matches!(mode, PrependMode::Evict));
+        if extras.is_empty() {
+            Ok(())
+        } else {
+            Err(FormatError::NoSpace(strategy.back(extras, self, section)))
+        }
+    }
+    /**
+    Replaces one line of a section with new text, leaving the rest of the section alone.
+    The index counts from the divider outwards, like the order lines are added in, so index 0 is the line next to the divider.
+    # Errors
+    Returns FormatError::NoLine if the section doesn't have a line at the index, and FormatError::NoSpace if the text doesn't trim to exactly one line.
+    Either way, the section isn't changed.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::{FormatError, Truncate};
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 8, 3).next_frame();
     let mut process = grid.into_process(grid::DividerStrategy::Beginning);
-    process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
-    assert_eq!(process.split_free_space(grid::Alignment::Plus, None, None), Some(grid::Grid{start_x: 0, start_y: 1, end_x: 10, end_y: 10}));
-    assert_eq!(process.end_y(), 1);
+    for item in ["> Open", "  Save", "  Quit"].iter() {
+        process.add_to_section(item.to_string(), &mut Truncate, grid::Alignment::Plus);
+    }
+    // Moves the highlight down one item.
+    process.set_line(grid::Alignment::Plus, 0, "  Open".to_string(), &mut Truncate).map_err(|_| ())?;
+    process.set_line(grid::Alignment::Plus, 1, "> Save".to_string(), &mut Truncate).map_err(|_| ())?;
+    assert_eq!(process.to_plain_lines(), vec!["  Open", "> Save", "  Quit"]);
+    let e = process.set_line(grid::Alignment::Plus, 3, "  Nope".to_string(), &mut Truncate).unwrap_err();
+    assert!(matches!(e, FormatError::NoLine(_)));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_line<T: TrimStrategy>(
+        &mut self,
+        section: Alignment,
+        index: usize,
+        text: T::Input,
+        strategy: &mut T,
+    ) -> Result<(), FormatError<T>> {
+        if index >= self.section(section).len() {
+            return Err(FormatError::NoLine(text));
+        }
+        let mut text = self.trim(text, strategy, section);
+        if text.len() != 1 {
+            return Err(FormatError::NoSpace(strategy.back(text, self, section)));
+        }
+        let line = text.remove(0);
+        self.cache_line(&line);
+        match section {
+            Alignment::Minus => self.minus[index] = line,
+            Alignment::Plus => self.plus[index] = line,
+        }
+        Ok(())
+    }
+    /**
+    Adds a label with a badge (like a count) at the right edge of its row, as in "Inbox          12".
+    The label is trimmed using the strategy, and the badge is put at the end of its first line, with fill characters in between.
+    If the label doesn't leave room for the badge, it's cut off with an ellipsis. A badge that's wider than the process is cut off too.
+    # Errors
+    This function returns an error, without adding anything, if the label doesn't entirely fit.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_labeled("Inbox".to_string(), "12", &mut Truncate, grid::Alignment::Plus);
+    process.add_labeled("Notifications".to_string(), "3", &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(process.row_at(0), Some("Inbox   12".to_string()));
+    assert_eq!(process.row_at(1), Some("Notific… 3".to_string()));
+    # #[cfg(feature = "unicode-width")] {
+    process.add_labeled("日本語日本語".to_string(), "12", &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(process.row_at(2), Some("日本語… 12".to_string()));
+    # }
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn add_labeled<T: TrimStrategy>(&mut self, label: T::Input, badge: &str, strategy: &mut T, section: Alignment) -> Result<(), FormatError<T>> {
+        let mut lines = self.trim(label, strategy, section);
+        if lines.len() > self.room(section) {
+            return Err(FormatError::NoSpace(strategy.back(lines, self, section)));
+        }
+        let width = self.width();
+        let badge = cut(badge, width);
+        let room = width - text_width(badge);
+        // The minus section's lines are stored from the bottom up, so the first line is at the end.
+        let first = match section {
+            Alignment::Minus => lines.last_mut(),
+            Alignment::Plus => lines.first_mut(),
+        };
+        if let Some(first) = first {
+            let label = first.unpadded();
+            let mut line = if text_width(label) > room {
+                // Leaves a space between the ellipsis and the badge.
+                let kept = room.saturating_sub(2);
+                let mut line = cut(label, kept).to_string();
+                if room > kept {
+                    line.push('…');
+                }
+                line
+            } else {
+                label.to_string()
+            };
+            let len = text_width(&line);
+            // The padding comes from the same positions of a blank line, like the padding of aligned lines.
+            line.extend(self.blank_text().chars().skip(len).chain(std::iter::repeat(self.fill_char)).take(room - len));
+            line.push_str(badge);
+            *first = TrimmedText::from(line);
+        }
+        for line in lines {
+            self.add_to_section_trimmed(line, section).expect("Space was checked beforehand");
+        }
+        Ok(())
+    }
+    /**
+    Finds out how content would fit into a section, without adding it. Returns how many of its lines would fit,
+    and how many wouldn't, in that order. The content is trimmed with the strategy and then thrown away.
+    The process itself isn't changed, but the strategy is: any state it keeps while trimming is updated
+    just as if the content had been added with add_to_section().
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Split;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    assert_eq!(process.plan("Short".to_string(), &mut Split, grid::Alignment::Plus), (1, 0));
+    assert_eq!(process.plan("A bit too long".to_string(), &mut Split, grid::Alignment::Plus), (2, 1));
+    assert_eq!(process.plan("Nothing fits".to_string(), &mut Split, grid::Alignment::Minus), (0, 3));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn plan<T: TrimStrategy>(&self, text: T::Input, strategy: &mut T, section: Alignment) -> (usize, usize) {
+        let lines = self.trim(text, strategy, section).len();
+        let fit = lines.min(self.room(section));
+        (fit, lines - fit)
+    }
+    /**
+    Finds out how many lines content would take up in a section, whether or not they'd fit, without adding it.
+    Like plan(), the process isn't changed, but the strategy is updated just as if the content had been added.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::WordWrap;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 1).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    assert_eq!(process.measure("Short".to_string(), &mut WordWrap, grid::Alignment::Plus), 1);
+    assert_eq!(process.measure("Not so short".to_string(), &mut WordWrap, grid::Alignment::Plus), 3);
+    assert!(process.to_plain_lines().is_empty());
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn measure<T: TrimStrategy>(&self, text: T::Input, strategy: &mut T, section: Alignment) -> usize {
+        self.trim(text, strategy, section).len()
+    }
+    /**
+    Reads lines from a reader into a section, one at a time, until the section is full or the reader runs out.
+    Lines are only read while there's room for them, so nothing past what fits is read.
+    Line endings are removed, and a last line without one is still added.
+    Returns how many lines were entirely added. A line that only partially fits is handled like it would be by add_to_section(),
+    and ends the filling.
+    # Errors
+    Returns an error if reading fails, including if a line isn't valid UTF-8. The lines read before the error stay in the section.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), std::io::Error>{
+    let mut grid = grid::Frame::new(0, 0, 5, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    let mut reader = "one\r\ntwo\nthree".as_bytes();
+    assert_eq!(process.fill_from_reader(&mut reader, &mut Truncate, grid::Alignment::Plus)?, 2);
+    assert_eq!(reader, "three".as_bytes());
+    assert_eq!(process.to_plain_text(), "one\ntwo".to_string());
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn fill_from_reader<R: BufRead, T: TrimStrategy<Input = String>>(&mut self, reader: R, strategy: &mut T, section: Alignment) -> io::Result<usize> {
+        let mut lines = reader.lines();
+        let mut added = 0;
+        while self.room(section) > 0 {
+            match lines.next() {
+                Some(line) => {
+                    if self.add_to_section(line?, strategy, section).is_err() {
+                        break;
+                    }
+                    added += 1;
+                }
+                None => break,
+            }
+        }
+        Ok(added)
+    }
+    /**
+    Adds a block of content to a section. A block is a group of lines that are added and removed together, like a multi-line list item.
+    Like add_to_section_lines(), the lines go top to bottom, even if Alignment::Minus is selected. Each input can become several lines.
+    The block is tracked as a range of lines in the section. If some of its lines are removed some other way (such as by set_divider()),
+    the block stops being tracked, and the rest of its lines stay where they are.
+    # Errors
+    If the entire block doesn't fit, nothing is added, and every input is given back (although it might be trimmed from trim methods).
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 6, 4).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    let first = process.add_block(vec!["Card 1".to_string(), "info".to_string()], &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+    let second = process.add_block(vec!["Card 2".to_string(), "more".to_string()], &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+    assert_eq!(process.block_height(first), Some(2));
+    assert!(process.add_block(vec!["No room".to_string()], &mut Truncate, grid::Alignment::Plus).is_err());
+    assert_eq!(process.remove_block(first).map(|lines| lines.len()), Some(2));
+    assert_eq!(process.block_height(first), None);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Card 2\nmore  \n      \n      \n".to_string(), output);
+    assert!(process.remove_block(second).is_some());
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn add_block<T: TrimStrategy>(&mut self, lines: Vec<T::Input>, strategy: &mut T, section: Alignment) -> Result<BlockId, Vec<T::Input>> {
+        let mut trimmed = lines.into_iter().map(|line| self.trim(line, strategy, section)).collect::<Vec<_>>();
+        if trimmed.iter().map(Vec::len).sum::<usize>() > self.room(section) {
+            return Err(trimmed.into_iter().map(|line| strategy.back(line, self, section)).collect());
+        }
+        if matches!(section, Alignment::Minus) {
+            // The minus section is printed in reverse, so the last line needs to go in first.
+            trimmed.reverse();
+        }
+        let len = trimmed.iter().map(Vec::len).sum::<usize>();
+        for line in trimmed.into_iter().flatten() {
+            self.add_to_section_trimmed(line, section).expect("Space was checked beforehand");
+        }
+        let id = BlockId(self.next_block);
+        self.next_block += 1;
+        // Counted from the end, since older lines might have been dropped to make room for the block.
+        let start = self.section(section).len() - len;
+        self.blocks.push(Block { id, section, start, len, link: None, color: None });
+        Ok(id)
+    }
+    /**
+    Adds content to a section, linking it to a uri. When printed, every line of the content becomes a hyperlink to the uri.
+    The uri isn't visible, so only the text counts towards the width of the process. Handlers that don't support links print the text normally.
+    If the content doesn't entirely fit, the lines that fit are still linked.
+    # Errors
+    This function returns an error if the content can't fit, just like add_to_section().
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # use grid_ui::out::{Action, SafeHandler};
+    struct Links(Vec<(String, String)>);
+    impl SafeHandler for Links {
+        type OutputDevice = ();
+        fn safe_handle(&mut self, _: &mut (), input: &Action) {
+            if let Action::Hyperlink { uri, text } = input {
+                self.0.push((uri.to_string(), text.to_string()));
+            }
+        }
+    }
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 4, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_link_to_section("Docs".to_string(), "https://docs.rs", &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("Text".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let mut links = Links(Vec::new());
+    process.print(&mut links, &mut ());
+    assert_eq!(links.0, vec![("https://docs.rs".to_string(), "Docs".to_string())]);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Docs\nText\n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn add_link_to_section<T: TrimStrategy>(&mut self, text: T::Input, uri: &str, strategy: &mut T, section: Alignment) -> Result<(), FormatError<T>> {
+        self.add_tagged(text, strategy, section, Some(uri.to_string()), None)
+    }
+    /**
+    Adds content to a section in a color. When printed, each line of the content is surrounded by Action::SetColor and Action::ResetColor.
+    Handlers that don't support colors ignore them, so they print the content just like any other content.
+    If the content doesn't entirely fit, the lines that fit are still colored.
+    # Errors
+    This function returns an error if the content can't fit, just like add_to_section().
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_colored_to_section("Error".to_string(), out::Color::Red, &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("Fine".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToAnsiString, &mut output)?;
+    assert_eq!("\x1b[31mError\x1b[0m\nFine \n".to_string(), output);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Error\nFine \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn add_colored_to_section<T: TrimStrategy>(
+        &mut self,
+        text: T::Input,
+        color: Color,
+        strategy: &mut T,
+        section: Alignment,
+    ) -> Result<(), FormatError<T>> {
+        self.add_tagged(text, strategy, section, None, Some(color))
+    }
+    #[doc(hidden)]
+    /// Adds content to a section, tracking the lines that were added as a block with a link or a color.
+    fn add_tagged<T: TrimStrategy>(
+        &mut self,
+        text: T::Input,
+        strategy: &mut T,
+        section: Alignment,
+        link: Option<String>,
+        color: Option<Color>,
+    ) -> Result<(), FormatError<T>> {
+        let text = self.trim(text, strategy, section);
+        let count = text.len();
+        let extras = self.add_all_trimmed(text, section);
+        // Counted from the end, since older lines might have been dropped to make room.
+        let len = count - extras.len();
+        let start = self.section(section).len() - len;
+        if len > 0 {
+            let id = BlockId(self.next_block);
+            self.next_block += 1;
+            self.blocks.push(Block {
+                id,
+                section,
+                start,
+                len,
+                link,
+                color,
+            });
+        }
+        if extras.is_empty() {
+            Ok(())
+        } else {
+            Err(FormatError::NoSpace(strategy.back(extras, self, section)))
+        }
+    }
+    /**
+    Removes the outermost line of a section (the one added last, which is farthest from the divider) and gives it back.
+    Returns None if the section is empty.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::{Truncate, TrimmedText};
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("One".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("Two".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(process.pop_line(grid::Alignment::Plus), Some(TrimmedText::from("Two  ".to_string())));
+    assert_eq!(process.pop_line(grid::Alignment::Minus), None);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("One  \n     \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn pop_line(&mut self, section: Alignment) -> Option<TrimmedText> {
+        let line = match section {
+            Alignment::Minus => self.minus.pop(),
+            Alignment::Plus => self.plus.pop(),
+        }?;
+        let len = self.section(section).len();
+        self.lines_removed(section, len, 1);
+        Some(line)
+    }
+    /// Removes a block of content, giving back its lines in the order they're stored in the section.
+    /// Returns None if the block doesn't exist (or is no longer being tracked). For an example, see add_block().
+    pub fn remove_block(&mut self, id: BlockId) -> Option<Vec<TrimmedText>> {
+        let block = self.blocks.iter().find(|block| block.id == id)?.clone();
+        let removed = match block.section {
+            Alignment::Minus => self.minus.drain(block.start..block.start + block.len).collect(),
+            Alignment::Plus => self.plus.drain(block.start..block.start + block.len).collect(),
+        };
+        self.lines_removed(block.section, block.start, block.len);
+        Some(removed)
+    }
+    /// Gets the number of lines in a block, or None if the block doesn't exist (or is no longer being tracked).
+    /// For an example, see add_block().
+    pub fn block_height(&self, id: BlockId) -> Option<usize> {
+        self.blocks.iter().find(|block| block.id == id).map(|block| block.len)
+    }
+    #[doc(hidden)]
+    /// Gets a section's content.
+    fn section(&self, section: Alignment) -> &Vec<TrimmedText> {
+        match section {
+            Alignment::Minus => &self.minus,
+            Alignment::Plus => &self.plus,
+        }
+    }
+    /**
+    Gets the fraction of the process's rows that are taken up by content, from 0.0 (empty) to 1.0 (full).
+    A process with no rows counts as empty.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 4).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Halfway);
+    process.add_to_section("Some stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(process.utilization(), 0.25);
+    assert_eq!(process.section_utilization(grid::Alignment::Plus), 0.5);
+    assert_eq!(process.section_utilization(grid::Alignment::Minus), 0.0);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn utilization(&self) -> f32 {
+        // Lines that are scrolled out of view don't take up any rows.
+        let shown = |section| self.section(section).len().min(self.capacity(section));
+        let bands = (0..self.bands.len()).map(|i| self.bands[i].lines.len().min(self.band_end(i) - self.bands[i].start.min(self.height())));
+        ratio(shown(Alignment::Minus) + shown(Alignment::Plus) + bands.sum::<usize>(), self.height())
+    }
+    /// Gets the fraction of a section's rows that are taken up by content, from 0.0 (empty) to 1.0 (full).
+    /// A section with no rows counts as empty. For an example, see utilization().
+    pub fn section_utilization(&self, section: Alignment) -> f32 {
+        let len = self.section(section).len();
+        ratio(len, len + self.free_lines(section))
+    }
+    /**
+    Gets how many lines of content are in a section.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut process = grid::Frame::new(0, 0, 5, 5).next_frame().into_process(grid::DividerStrategy::Pos(2));
+    process.add_to_section("One".to_string(), &mut Truncate, grid::Alignment::Minus);
+    process.add_to_section("Two".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("Three".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(process.lines_used(grid::Alignment::Minus), 1);
+    assert_eq!(process.free_lines(grid::Alignment::Minus), 1);
+    assert_eq!(process.lines_used(grid::Alignment::Plus), 2);
+    assert_eq!(process.free_lines(grid::Alignment::Plus), 1);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn lines_used(&self, section: Alignment) -> usize {
+        self.section(section).len()
+    }
+    /// Gets how many more lines can be added to a section. For an example, see lines_used().
+    pub fn free_lines(&self, section: Alignment) -> usize {
+        match section {
+            Alignment::Minus => self.divider.saturating_sub(self.minus.len()),
+            Alignment::Plus => self.plus_end().saturating_sub(self.divider).saturating_sub(self.plus.len()),
+        }
+    }
+    #[doc(hidden)]
+    /// Gets how many more lines can be added to a section before it overflows. Sections that scroll never overflow,
+    /// and sections that drop their oldest lines can always fit as many lines as they can show.
+    fn room(&self, section: Alignment) -> usize {
+        match self.overflow {
+            OverflowMode::Error => self.free_lines(section),
+            OverflowMode::Scroll => usize::MAX,
+            OverflowMode::DropOldest => self.capacity(section),
+        }
+    }
+    #[doc(hidden)]
+    /// Removes the lines furthest from the divider that don't fit in a section, if the process drops its oldest lines or evict is set.
+    /// Used after inserting next to the divider, where the oldest lines are on the outside.
+    fn drop_outermost(&mut self, section: Alignment, evict: bool) {
+        if !evict && !matches!(self.overflow, OverflowMode::DropOldest) {
+            return;
+        }
+        let capacity = self.capacity(section);
+        let excess = self.section(section).len().saturating_sub(capacity);
+        if excess > 0 {
+            match section {
+                Alignment::Minus => self.minus.truncate(capacity),
+                Alignment::Plus => self.plus.truncate(capacity),
+            }
+            self.lines_removed(section, capacity, excess);
+        }
+    }
+    #[doc(hidden)]
+    /// Updates the blocks after count lines were removed from a section, starting at start.
+    /// Blocks that lost lines stop being tracked, and blocks after the removed lines are moved back.
+    fn lines_removed(&mut self, section: Alignment, start: usize, count: usize) {
+        self.blocks.retain(|block| block.section != section || block.start + block.len <= start || block.start >= start + count);
+        for block in self.blocks.iter_mut().filter(|block| block.section == section && block.start >= start) {
+            block.start -= count;
+        }
+    }
+    #[doc(hidden)]
+    /// Updates the blocks after count lines were inserted into a section at a position.
+    /// Blocks that were split apart stop being tracked, and blocks after the inserted lines are moved forward.
+    fn lines_inserted(&mut self, section: Alignment, at: usize, count: usize) {
+        self.blocks.retain(|block| block.section != section || block.start >= at || block.start + block.len <= at);
+        for block in self.blocks.iter_mut().filter(|block| block.section == section && block.start >= at) {
+            block.start += count;
+        }
+    }
+    /**
+    Reverses the order of a section's lines, so that they're printed in the opposite order, without trimming them again.
+    Lines are stored from the divider outwards, which is why the minus section is printed from the bottom up. Reversing either section
+    flips the order it's printed in on the screen: the line that was furthest from the divider ends up next to it, and the other way around.
+    Blocks are kept, and stay together.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 4).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    for line in ["One", "Two", "Three"].iter() {
+        process.add_to_section(line.to_string(), &mut Truncate, grid::Alignment::Plus);
+    }
+    process.reverse_section(grid::Alignment::Plus);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Three\nTwo  \nOne  \n     \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn reverse_section(&mut self, section: Alignment) {
+        let lines = match section {
+            Alignment::Minus => &mut self.minus,
+            Alignment::Plus => &mut self.plus,
+        };
+        lines.reverse();
+        let len = lines.len();
+        for block in self.blocks.iter_mut().filter(|block| block.section == section) {
+            block.start = len - block.start - block.len;
+        }
+    }
+    /**
+    Clears the process, allowing it to be re-used. 
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Ignore;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 1).next_frame(); // creates a grid with one line
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
+    assert!(process.add_to_section("No more".to_string(), &mut Ignore, grid::Alignment::Plus).is_err());
+    process.clear(grid::DividerStrategy::Beginning);
+    assert!(process.add_to_section("Some more".to_string(), &mut Ignore, grid::Alignment::Plus).is_ok());
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn clear(&mut self, new_strategy: DividerStrategy) {
+        self.divider = new_strategy.resolve(self.height());
+        self.minus.clear();
+        self.plus.clear();
+        for band in self.bands.iter_mut() {
+            band.lines.clear();
+        }
+        self.blocks.clear();
+        self.writes.clear();
+        self.scroll_minus = 0;
+        self.scroll_plus = 0;
+        self.invalidate();
+    }
+    /**
+    Writes text onto a row, starting at a column, using an absolute y position. The column is counted from the left edge of the process.
+    Text that goes past the right edge of the process is cut off, and writing outside of the process does nothing.
+    Written text takes precedence over the section content and blank space on the row it covers, and later writes cover earlier ones.
+    It's tied to the row rather than to the content, so it stays in place when the content under it changes or moves.
+    Everything written stays until clear_writes() or clear() is called.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("  status".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.write_at(0, 0, "*");
+    process.write_at(0, 8, "[ok]");
+    process.write_at(1, 9, "!");
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("* status[o\n         !\n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn write_at(&mut self, abs_y: usize, col: usize, text: &str) {
+        let y = match abs_y.checked_sub(self.start_y) {
+            Some(y) if y < self.height() && col < self.width() => y,
+            _ => return,
+        };
+        let text = cut(text, self.width() - col).to_string();
+        self.writes.push((y, col, text));
+    }
+    /// Removes everything written with write_at(), leaving the process's content as it is.
+    pub fn clear_writes(&mut self) {
+        self.writes.clear();
+    }
+    /**
+    Marks where the terminal's cursor should go, as a column of one of a section's lines, so that it can be found with cursor_position().
+    Like set_line(), the line index counts from the divider outwards, and the column counts from the start of the line's text.
+    Only one place can be marked at once, so this replaces the last one. The mark stays on the same index when lines are added or removed.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(2, 3, 12, 6).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Pos(2));
+    process.add_to_section("Title".to_string(), &mut Truncate, grid::Alignment::Minus);
+    process.add_to_section("Name?".to_string(), &mut Truncate, grid::Alignment::Minus);
+    process.add_to_section("> Thea".to_string(), &mut Truncate, grid::Alignment::Plus);
+    // Puts the cursor at the end of the input.
+    process.set_cursor_anchor(grid::Alignment::Plus, 0, 6);
+    assert_eq!(process.cursor_position(), Some((8, 5)));
+    // The minus section is printed from the bottom up, so its first line is just above the divider.
+    process.set_cursor_anchor(grid::Alignment::Minus, 0, 0);
+    assert_eq!(process.cursor_position(), Some((2, 4)));
+    process.set_h_align(grid::HAlign::Right);
+    assert_eq!(process.cursor_position(), Some((7, 4)));
+    process.clear_cursor_anchor();
+    assert_eq!(process.cursor_position(), None);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_cursor_anchor(&mut self, section: Alignment, line_index: usize, col: usize) {
+        self.cursor_anchor = Some((section, line_index, col));
+    }
+    /// Removes the place marked with set_cursor_anchor(), so that cursor_position() gives back None.
+    pub fn clear_cursor_anchor(&mut self) {
+        self.cursor_anchor = None;
+    }
+    /// Finds the absolute location of the place marked with set_cursor_anchor(), which is where a handler should move the cursor after printing.
+    /// This accounts for where the line is printed, and for the horizontal alignment, line number gutter, and text direction.
+    /// Columns past the edge of the process are moved back to its last column.
+    /// Returns None if nothing is marked, or if the marked line isn't shown (because it doesn't exist, is hidden, or is scrolled away).
+    /// For an example, see set_cursor_anchor().
+    pub fn cursor_position(&self) -> Option<(usize, usize)> {
+        let (section, line_index, col) = self.cursor_anchor?;
+        let (y, line) = self.rows().into_iter().enumerate().find_map(|(y, row)| match row {
+            Row::Content(s, stored, line) if s == section && stored == line_index => Some((y, line)),
+            _ => None,
+        })?;
+        let gutter = if self.gutter == 0 { 0 } else { self.gutter.min(self.width()) };
+        let x = (gutter + self.align_offset(text_width(line.unpadded())) + col).min(self.width().checked_sub(1)?);
+        let x = match self.direction {
+            TextDirection::LeftToRight => x,
+            TextDirection::RightToLeft => self.width() - 1 - x,
+        };
+        Some((self.start_x + x, self.start_y + y))
+    }
+    /**
+    Runs several mutations on the process as one batch.
+    Anything derived from the process's content is only recomputed once, after the closure returns,
+    instead of after every individual mutation. Currently, this is the line cache (see set_line_cache()).
+    Mutations inside the closure behave exactly as they would outside of it.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Ignore;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.batch(|p| {
+        assert!(p.is_batching());
+        p.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
+        p.add_to_section("More stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
+    });
+    assert!(!process.is_batching());
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Some stuff\nMore stuff\n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn batch<F: FnOnce(&mut DrawProcess)>(&mut self, f: F) {
+        self.batching += 1;
+        f(self);
+        self.batching -= 1;
+        self.invalidate();
+    }
+    /**
+    Turns the line cache on or off. The cache is off by default.
+    When it's on, the process remembers the text printed for each line of content, instead of padding it again every time it's printed.
+    This trades memory for speed, and is only useful if lines are aligned to the center or to the right, as other lines don't need padding.
+    The cache is updated as content is added, and thrown out and rebuilt whenever the alignment changes.
+    Lines that are removed from the process are thrown out of the cache every so often, so it stays about as big as the content.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 6, 1).next_frame();
+    let mut process = grid.into_process_aligned(grid::DividerStrategy::Beginning, grid::HAlign::Right);
+    process.set_line_cache(true);
+    process.add_to_section("1".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("     1\n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_line_cache(&mut self, enabled: bool) {
+        self.line_cache = LineCache(if enabled { Some(HashMap::new()) } else { None });
+        self.invalidate();
+    }
+    #[doc(hidden)]
+    /// Rebuilds anything derived from the content and settings, unless a batch is running (in which case the batch does it once it's done).
+    fn invalidate(&mut self) {
+        if self.batching != 0 {
+            return;
+        }
+        if let Some(mut cache) = self.line_cache.0.take() {
+            cache.clear();
+            if !matches!(self.h_align, HAlign::Left) {
+                for line in self.minus.iter().chain(self.plus.iter()) {
+                    cache.insert(line.unpadded().to_string(), self.align_line(line).into_owned());
+                }
+            }
+            self.line_cache.0 = Some(cache);
+        }
+    }
+    #[doc(hidden)]
+    /// Adds a single line to the line cache, if it's on.
+    fn cache_line(&mut self, line: &TrimmedText) {
+        if self.batching != 0 || matches!(self.h_align, HAlign::Left) || self.line_cache.0.is_none() {
+            return;
+        }
+        let aligned = self.align_line(line).into_owned();
+        let lines = self.minus.len() + self.plus.len() + 1;
+        if let Some(cache) = &mut self.line_cache.0 {
+            cache.insert(line.unpadded().to_string(), aligned);
+            // Lines that were removed from the process are still in the cache. Once they take up more than half of it,
+            // they're thrown out, so the cache never holds more than about twice the lines in the process.
+            if cache.len() > 2 * lines {
+                let kept = self.minus.iter().chain(self.plus.iter()).chain(Some(line)).map(TrimmedText::unpadded).collect::<HashSet<_>>();
+                cache.retain(|key, _| kept.contains(key.as_str()));
+            }
+        }
+    }
+    /**
+    Sets the horizontal alignment, which decides where each line of content is placed within its row.
+    Blank space added by trim strategies is moved to the other side (or both sides) of the line.
+    Centered lines that can't be split evenly have the extra space on the right, unless set_center_bias() says otherwise.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("stuff".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.set_h_align(grid::HAlign::Right);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("      Some\n     stuff\n".to_string(), output);
+    process.set_h_align(grid::HAlign::Center);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("   Some   \n  stuff   \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_h_align(&mut self, h_align: HAlign) {
+        self.h_align = h_align;
+        self.invalidate();
+    }
+    /**
+    Gets the horizontal alignment, which is HAlign::Left unless it's been changed with set_h_align() or Grid::into_process_aligned().
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    assert_eq!(process.h_align(), grid::HAlign::Left);
+    process.set_h_align(grid::HAlign::Center);
+    assert_eq!(process.h_align(), grid::HAlign::Center);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn h_align(&self) -> HAlign {
+        self.h_align
+    }
+    /**
+    Sets the vertical alignment, which decides where each section's content is placed within the rows on its side of the divider.
+    This only changes where the blank rows go when the process is printed, so the divider still separates the sections,
+    and shoving content or moving the divider places it again within the new space.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 5).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Hi".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.set_v_align(grid::VAlign::Center);
+    assert_eq!(process.v_align(), grid::VAlign::Center);
+    let lines: Vec<String> = process.rendered_lines().collect();
+    assert_eq!(lines, vec!["     ", "     ", "Hi   ", "     ", "     "]);
+    process.set_v_align(grid::VAlign::Edge);
+    let lines: Vec<String> = process.rendered_lines().collect();
+    assert_eq!(lines, vec!["     ", "     ", "     ", "     ", "Hi   "]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_v_align(&mut self, v_align: VAlign) {
+        self.v_align = v_align;
+    }
+    /// Gets the vertical alignment, which is VAlign::Divider unless it's been changed with set_v_align().
+    pub fn v_align(&self) -> VAlign {
+        self.v_align
+    }
+    /**
+    Sets which way rows are laid out. Right to left rows are mirrored: they start at the right edge of the process,
+    so the blank space added by trim strategies (and the line number gutter) ends up on the left, and the characters are printed in reverse order.
+    The fill pattern isn't mirrored, so it still lines up with the processes around it.
+    This is a basic flip of each row, for terminals that don't reorder right to left text themselves. It isn't the bidirectional algorithm:
+    left to right text (like numbers) inside a right to left row is reversed too, and horizontal alignment and columns given to write_at() are mirrored along with everything else.
+    Characters are reversed by grapheme, so combining characters stay attached to the right character.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 6, 1).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("שלום".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.set_direction(grid::TextDirection::RightToLeft);
+    assert_eq!(process.direction(), grid::TextDirection::RightToLeft);
+    assert_eq!(process.row_at(0), Some("  םולש".to_string()));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_direction(&mut self, direction: TextDirection) {
+        self.direction = direction;
+        // Aligned lines are padded with the fill pattern, which is laid out differently in each direction.
+        self.invalidate();
+    }
+    /// Gets which way rows are laid out, which is TextDirection::LeftToRight unless it's been changed with set_direction().
+    pub fn direction(&self) -> TextDirection {
+        self.direction
+    }
+    /// Gets which side gets the extra blank space when a centered line can't be split evenly. See set_center_bias().
+    pub fn center_bias(&self) -> CenterBias {
+        self.center_bias
+    }
+    /**
+    Sets which side gets the extra blank space when a centered line can't be split evenly. This is CenterBias::Right by default.
+    It only matters when the horizontal alignment is HAlign::Center.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 6, 1).next_frame();
+    let mut process = grid.into_process_aligned(grid::DividerStrategy::Beginning, grid::HAlign::Center);
+    process.add_to_section("odd".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(process.row_at(0), Some(" odd  ".to_string()));
+    process.set_center_bias(grid::CenterBias::Left);
+    assert_eq!(process.row_at(0), Some("  odd ".to_string()));
+    process.set_center_bias(grid::CenterBias::Right);
+    assert_eq!(process.row_at(0), Some(" odd  ".to_string()));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_center_bias(&mut self, bias: CenterBias) {
+        self.center_bias = bias;
+        self.invalidate();
+    }
+    /**
+    Shows or hides a section's content. Hidden content is kept, but printed as blank space until the section is shown again.
+    Hiding a section doesn't change how much content either section can hold. By default, the hidden content's rows stay blank;
+    see set_collapse_hidden() to let the other section's content move into them instead.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Halfway);
+    process.add_to_section("Top".to_string(), &mut Truncate, grid::Alignment::Minus);
+    process.add_to_section("Down".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.set_visible(grid::Alignment::Minus, false);
+    assert!(!process.is_visible(grid::Alignment::Minus));
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("     \nDown \n".to_string(), output);
+    process.set_visible(grid::Alignment::Minus, true);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Top  \nDown \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_visible(&mut self, section: Alignment, visible: bool) {
+        match section {
+            Alignment::Minus => self.hidden_minus = !visible,
+            Alignment::Plus => self.hidden_plus = !visible,
+        }
+    }
+    /// Whether a section's content is shown. Sections are shown by default.
+    pub fn is_visible(&self, section: Alignment) -> bool {
+        match section {
+            Alignment::Minus => !self.hidden_minus,
+            Alignment::Plus => !self.hidden_plus,
+        }
+    }
+    /**
+    Decides whether hidden sections take up space when printing. This is off by default, leaving blank space where the hidden content would be.
+    When it's on, a hidden section takes up no rows, and the other section is printed as if the divider was at the hidden section's outer edge.
+    This only affects printing. The content each section can hold stays the same, so showing the section again puts everything back where it was.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Halfway);
+    process.add_to_section("Top".to_string(), &mut Truncate, grid::Alignment::Minus);
+    process.add_to_section("Down".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.set_visible(grid::Alignment::Minus, false);
+    process.set_collapse_hidden(true);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Down \n     \n     \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_collapse_hidden(&mut self, collapse: bool) {
+        self.collapse_hidden = collapse;
+    }
+    /**
+    Decides whether runs of empty lines of content are collapsed when printing. This is off by default.
+    When it's on, each run of consecutive empty lines is printed as a single empty line, and everything below it moves up.
+    The rows freed up this way are printed blank at the bottom of the process.
+    This only affects printing, so the process still needs room for every line, and lines don't stay on a fixed row:
+    row_at() and char_at() describe what's actually printed.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 3, 5).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    for line in ["One", "", "", "", "Two"].iter() {
+        process.add_to_section(line.to_string(), &mut Truncate, grid::Alignment::Plus);
+    }
+    process.set_collapse_blanks(true);
+    assert_eq!(process.row_at(2), Some("Two".to_string()));
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("One\n   \nTwo\n   \n   \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_collapse_blanks(&mut self, collapse: bool) {
+        self.collapse_blanks = collapse;
+    }
+    /**
+    Sets a shadow under the divider. When it's set, the first blank row on the plus side of the divider is filled with the shadow character
+    instead of blank space - right under a header in the minus section, for instance. The shadow is off (None) by default.
+    It's only decoration: it doesn't take up any of the plus section's space, and isn't part of the content.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Pos(1));
+    process.add_to_section("Title".to_string(), &mut Truncate, grid::Alignment::Minus);
+    process.set_divider_shadow(Some('░'));
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Title\n░░░░░\n     \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_divider_shadow(&mut self, shadow: Option<char>) {
+        self.divider_shadow = shadow;
+    }
+    /**
+    Decides whether printing is surrounded by Action::BeginSyncUpdate and Action::EndSyncUpdate. This is off by default.
+    Terminals that support synchronized output then show the whole process at once, instead of drawing it row by row.
+    Only turn this on when printing to a terminal - handlers for other outputs may not expect these actions.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::{Action, SafeHandler};
+    struct Count(usize);
+    impl SafeHandler for Count {
+        type OutputDevice = ();
+        fn safe_handle(&mut self, _: &mut (), input: &Action) {
+            if matches!(input, Action::BeginSyncUpdate | Action::EndSyncUpdate) {
+                self.0 += 1;
+            }
+        }
+    }
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    let mut count = Count(0);
+    process.print(&mut count, &mut ())?;
+    assert_eq!(count.0, 0);
+    process.set_sync_updates(true);
+    process.print(&mut count, &mut ())?;
+    assert_eq!(count.0, 2);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_sync_updates(&mut self, sync: bool) {
+        self.sync_updates = sync;
+    }
+    /**
+    Sets zebra striping, which gives rows of content alternating background colors. Striping is off (None) by default.
+    The first row of content printed (counting from the top) gets the first color, the next one gets the second color, and so on.
+    Only rows of content are counted, so the stripes stay the same wherever the content ends up on the screen.
+    Blank rows keep the default background, and don't affect which color the next row of content gets.
+    Each striped row is printed between Action::SetBackground and Action::ResetColor.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::{Action, Color, SafeHandler};
+    # use grid_ui::trim::Truncate;
+    struct Backgrounds(Vec<Color>);
+    impl SafeHandler for Backgrounds {
+        type OutputDevice = ();
+        fn safe_handle(&mut self, _: &mut (), input: &Action) {
+            if let Action::SetBackground(color) = input {
+                self.0.push(*color);
+            }
+        }
+    }
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 4).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Halfway);
+    process.add_to_section("Top".to_string(), &mut Truncate, grid::Alignment::Minus);
+    process.add_to_section("One".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("Two".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.set_zebra(Some((Color::Black, Color::BrightBlack)));
+    let mut backgrounds = Backgrounds(Vec::new());
+    process.print(&mut backgrounds, &mut ())?;
+    assert_eq!(backgrounds.0, vec![Color::Black, Color::BrightBlack, Color::Black]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_zebra(&mut self, colors: Option<(Color, Color)>) {
+        self.zebra = colors;
+    }
+    /**
+    Sets the width of the line number gutter, which shows each line's number at the start of its row. A width of 0 (the default) turns it off.
+    The gutter holds the number, right-aligned, and a space after it. It takes its columns from the content,
+    so the end of each line is cut off - trim content for a process that's narrower by the gutter's width.
+    Lines are numbered from 1 by where they're stored: the minus section's lines come first, from the top, followed by the plus section's lines.
+    That means numbers describe the stored content rather than the visible rows, so they stay with their lines wherever the lines are printed.
+    Numbers that don't fit in the gutter only show their last digits.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 8, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("fn main".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("{}".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.set_line_numbers(3);
+    assert_eq!(process.row_at(0), Some(" 1 fn ma".to_string()));
+    assert_eq!(process.row_at(1), Some(" 2 {}   ".to_string()));
+    assert_eq!(process.row_at(2), Some("        ".to_string()));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_line_numbers(&mut self, gutter: usize) {
+        self.gutter = gutter;
+    }
+    /**
+    Decides what happens to content that doesn't fit in a section. By default, it's given back in an error (OverflowMode::Error).
+    With OverflowMode::Scroll, every line is kept, but each section only shows as many lines as fit in it,
+    starting from its scroll position (see set_scroll()).
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 6, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.set_overflow(grid::OverflowMode::Scroll);
+    for line in ["one", "two", "three", "four"].iter() {
+        process.add_to_section(line.to_string(), &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+    }
+    assert_eq!(process.lines_used(grid::Alignment::Plus), 4);
+    assert_eq!(process.to_plain_lines(), vec!["one", "two"]);
+    process.set_scroll(grid::Alignment::Plus, 1);
+    assert_eq!(process.to_plain_lines(), vec!["two", "three"]);
+    process.scroll_to_bottom();
+    assert_eq!(process.to_plain_lines(), vec!["three", "four"]);
+    assert_eq!(process.scroll(grid::Alignment::Plus), 2);
+    # Ok(())
+    # }
+    ```
+    With OverflowMode::DropOldest, a section that's full removes the lines that were added first whenever new content is added,
+    so it always shows the newest content. Adding to the front of a section (see add_to_section_front()) drops the lines on the outside instead.
+    Content that's too big for the whole section still doesn't fit: like add_to_section() normally does, it adds as many lines as the section
+    can show, and gives back the rest in an error.
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::{Truncate, WordWrap};
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 6, 2).next_frame();
+    let mut log = grid.into_process(grid::DividerStrategy::Beginning);
+    log.set_overflow(grid::OverflowMode::DropOldest);
+    for line in ["one", "two", "three", "four"].iter() {
+        log.add_to_section(line.to_string(), &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+    }
+    assert_eq!(log.lines_used(grid::Alignment::Plus), 2);
+    assert_eq!(log.to_plain_lines(), vec!["three", "four"]);
+    let error = log.add_to_section("aaaaaa bbbbbb cccccc".to_string(), &mut WordWrap, grid::Alignment::Plus).unwrap_err();
+    assert_eq!(error.recovered(), Some(&"cccccc".to_string()));
+    assert_eq!(log.to_plain_lines(), vec!["aaaaaa", "bbbbbb"]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_overflow(&mut self, mode: OverflowMode) {
+        self.overflow = mode;
+    }
+    /// Scrolls a section, so that it shows its lines starting from the offset. Offsets count from the divider outwards,
+    /// like the order lines are added in, so a minus section scrolls upwards. Offsets past the last full window are moved back to it.
+    /// Scrolling only matters when a section has more lines than fit, which needs OverflowMode::Scroll. For an example, see set_overflow().
+    pub fn set_scroll(&mut self, section: Alignment, offset: usize) {
+        let offset = offset.min(self.section(section).len().saturating_sub(self.capacity(section)));
+        match section {
+            Alignment::Minus => self.scroll_minus = offset,
+            Alignment::Plus => self.scroll_plus = offset,
+        }
+    }
+    /// Gets where a section is scrolled to. For an example, see set_overflow().
+    pub fn scroll(&self, section: Alignment) -> usize {
+        let offset = match section {
+            Alignment::Minus => self.scroll_minus,
+            Alignment::Plus => self.scroll_plus,
+        };
+        offset.min(self.section(section).len().saturating_sub(self.capacity(section)))
+    }
+    /// Scrolls both sections to the bottom of the process: the plus section shows its last lines, and the minus section shows the lines
+    /// next to the divider. Call this after adding lines to follow the end of a log. For an example, see set_overflow().
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll_minus = 0;
+        self.set_scroll(Alignment::Plus, usize::MAX);
+    }
+    /// Whether the process is currently inside a call to batch().
+    pub fn is_batching(&self) -> bool {
+        self.batching != 0
+    }
+    /**
+    Gives up free space in the Y direction, producing a grid if there's free space to give up. 
+    Will take up to max_taken lines of space. If max_taken is set to None, it will take up to the divider line. 
+    Will leave at least min_left lines TOTAL (in either direction). Might leave some blank lines. 
+    Returns this space in a grid if there is any. If the process is already full, nothing will be returned. 
+    If the divider is past the bottom of the process, the plus side has no free space, so nothing will be returned for it either.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Ignore;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 10).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
+    assert_eq!(process.split_free_space(grid::Alignment::Plus, None, None), Some(grid::Grid{start_x: 0, start_y: 1, end_x: 10, end_y: 10}));
+    assert_eq!(process.end_y(), 1);
+    // The divider can't be past the bottom of the process for the plus side to have any space.
+    let mut process = grid::Frame::new(0, 0, 10, 10).next_frame().into_process(grid::DividerStrategy::Pos(15));
+    process.shove(grid::Alignment::Plus);
+    assert_eq!(process.split_free_space(grid::Alignment::Plus, None, None), None);
     # Ok(())
     # }
     ```
@@ -360,199 +2162,1406 @@ impl DrawProcess {
     pub fn split_free_space(&mut self, a: Alignment, min_left: Option<usize>, max_taken: Option<usize>) -> Option<Grid> {
         match a {
             Alignment::Minus => {
-                let space = self.divider;
-                let mut space_occupied = self.minus.len();
-                if let Some(val) = min_left {
-                    space_occupied = space_occupied.max(val);
+                let space = self.divider;
+                let mut space_occupied = self.minus.len();
+                if let Some(val) = min_left {
+                    space_occupied = space_occupied.max(val);
+                }
+                let mut total_space = space.saturating_sub(space_occupied);
+                if let Some(val) = max_taken {
+                    total_space = total_space.min(val);
+                }
+                if total_space != 0 {
+                    self.start_y += total_space;
+                    // The divider and the writes are relative to the top, so they move back to stay where they were.
+                    self.divider -= total_space;
+                    for band in self.bands.iter_mut() {
+                        band.start -= total_space;
+                    }
+                    self.writes.retain(|(y, _, _)| *y >= total_space);
+                    for write in self.writes.iter_mut() {
+                        write.0 -= total_space;
+                    }
+                    Some(Grid {
+                        start_x: self.start_x,
+                        start_y: self.start_y - total_space,
+                        end_x: self.end_x,
+                        end_y: self.start_y,
+                    })
+                } else {
+                    None
+                }
+            },
+            Alignment::Plus => {
+                #[cfg(feature = "debug-trace")]
+                log::debug!("END: {} START: {} DIVIDE: {}", self.end_y, self.start_y, self.divider);
+                // The bottom of a process with bands belongs to its last band, not to the plus section.
+                if !self.bands.is_empty() {
+                    return None;
+                }
+                let space = self.height().checked_sub(self.divider)?;
+                let mut space_occupied = self.plus.len();
+                #[cfg(feature = "debug-trace")]
+                log::debug!("SPACE: {} OCCUPIED: {}", space, space_occupied);
+                if let Some(val) = min_left {
+                    space_occupied = space_occupied.max(val);
+                }
+                let mut total_space = space.saturating_sub(space_occupied);
+                #[cfg(feature = "debug-trace")]
+                log::debug!("TOTAL: {}", total_space);
+                if let Some(val) = max_taken {
+                    total_space = total_space.min(val);
+                }
+                if total_space != 0 {
+                    self.end_y -= total_space;
+                    Some(Grid {
+                        start_x: self.start_x,
+                        start_y: self.end_y,
+                        end_x: self.end_x,
+                        end_y: self.end_y + total_space,
+                    })
+                } else {
+                    None
+                }
+            },
+        }
+    }
+    /**
+    Gives up free space in the X direction, producing a grid out of the columns on the right that nothing is printed in.
+    Will take up to max_taken columns, and will leave at least min_left columns. The space is found using the longest line of content
+    (including the line numbers, if there are any, and anything written with write_at()), so it's the same for every row.
+    The grid that's returned has the same Y range as the process, so it can be given back with extend().
+    Returns None if there's no free space, including when the content already takes up the entire width.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Short".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("Longer".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let free = process.split_free_space_x(None, Some(3));
+    assert_eq!(free, Some(grid::Grid {start_x: 7, start_y: 0, end_x: 10, end_y: 2}));
+    assert_eq!(process.width(), 7);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Short  \nLonger \n".to_string(), output);
+    assert_eq!(process.split_free_space_x(Some(6), None).map(|grid| grid.start_x), Some(6));
+    assert_eq!(process.split_free_space_x(None, None), None);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn split_free_space_x(&mut self, min_left: Option<usize>, max_taken: Option<usize>) -> Option<Grid> {
+        let gutter = if self.gutter == 0 { 0 } else { self.gutter.min(self.width()) };
+        let content = self.minus.iter().chain(self.plus.iter()).map(|line| gutter + text_width(line.unpadded()));
+        let content = content.chain(self.bands.iter().flat_map(|band| &band.lines).map(|line| text_width(line.unpadded())));
+        let writes = self.writes.iter().map(|(_, col, text)| col + text_width(text));
+        let used = content.chain(writes).max().unwrap_or(0).max(min_left.unwrap_or(0));
+        let mut total_space = self.width().saturating_sub(used);
+        if let Some(val) = max_taken {
+            total_space = total_space.min(val);
+        }
+        if total_space == 0 {
+            return None;
+        }
+        self.end_x -= total_space;
+        let width = self.width();
+        // The lines were padded to the old width, so the padding that's now outside of the process is removed.
+        for line in self.lines_mut() {
+            line.0 = cut(&line.0, width).to_string();
+            line.1 = line.1.min(line.0.len());
+        }
+        self.rebuild_blank();
+        self.invalidate();
+        Some(Grid {
+            start_x: self.end_x,
+            start_y: self.start_y,
+            end_x: self.end_x + total_space,
+            end_y: self.end_y,
+        })
+    }
+    /**
+    Extends the grid in the either direction, either positive or negative, if the input is compatible
+    (ie grids are next to each other and of similar dimensions)
+    Grids can be added above or below (with the same X range), or to the left or right (with the same Y range).
+    When the process gets wider, its lines are padded with blank space to the new width.
+    Content and text written with write_at() stay where they were on the screen, so rows added above go to the minus section,
+    and columns added on the left are blank space at the start of every line.
+    If the two grids are incompatible, it returns an error and gives the grid back. 
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Ignore;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 10).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
+    let free_space = process.split_free_space(grid::Alignment::Plus, None, None).ok_or(())?;
+    assert_eq!(process.end_y(), 1);
+    assert!(process.extend(free_space).is_ok());
+    assert_eq!(process.end_y(), 10);
+    let incompatible_grid = grid::Frame::new(4, 4, 8, 8).next_frame();
+    assert!(process.extend(incompatible_grid).is_err());
+    # Ok(())
+    # }
+    ```
+    Giving back free columns:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 8, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let free_space = process.split_free_space_x(None, None).ok_or(())?;
+    assert_eq!(process.width(), 4);
+    assert!(process.extend(free_space).is_ok());
+    assert_eq!(process.width(), 8);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Some    \n        \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    Adding rows above and columns on the left:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut process = grid::Frame::new(3, 2, 6, 3).next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Hi".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.write_at(2, 2, "X");
+    assert!(process.extend(grid::Grid {start_x: 3, start_y: 1, end_x: 6, end_y: 2}).is_ok());
+    assert!(process.extend(grid::Grid {start_x: 0, start_y: 1, end_x: 3, end_y: 3}).is_ok());
+    assert_eq!(process.row_at(2), Some("   HiX".to_string()));
+    assert_eq!(process.row_at(1), Some("      ".to_string()));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn extend(&mut self, grid: Grid) -> Result<(), Grid> {
+        if self.start_x == grid.start_x && self.end_x == grid.end_x {
+            if self.end_y == grid.start_y {
+                self.end_y = grid.end_y;
+                return Ok(())
+            }
+            if self.start_y == grid.end_y {
+                // The divider and the writes are relative to the top, so they move down to stay where they were.
+                let added = grid.end_y - grid.start_y;
+                self.start_y = grid.start_y;
+                self.divider += added;
+                for band in self.bands.iter_mut() {
+                    band.start += added;
+                }
+                for write in self.writes.iter_mut() {
+                    write.0 += added;
+                }
+                return Ok(())
+            }
+        }
+        if self.start_y == grid.start_y && self.end_y == grid.end_y && (self.end_x == grid.start_x || self.start_x == grid.end_x) {
+            // Columns added on the left go before the lines and the writes, so that they stay where they were.
+            let added_left = if self.end_x == grid.start_x {
+                self.end_x = grid.end_x;
+                0
+            } else {
+                self.start_x = grid.start_x;
+                grid.end_x - grid.start_x
+            };
+            let width = self.width();
+            for line in self.lines_mut() {
+                line.0.insert_str(0, &" ".repeat(added_left));
+                line.1 += added_left;
+                let padding = width.saturating_sub(text_width(&line.0));
+                line.0.extend(std::iter::repeat(' ').take(padding));
+            }
+            for write in self.writes.iter_mut() {
+                write.1 += added_left;
+            }
+            self.rebuild_blank();
+            self.invalidate();
+            return Ok(())
+        }
+        Err(grid)
+    }
+    /**
+    Merges another process into this one, if it's directly above or below this one with the same X range (like extend()).
+    The bounds grow to cover both processes, and the other process's content is added to the section on its side of the divider:
+    content from a process below goes after this process's plus section, and content from a process above goes before its minus section.
+    The divider follows this process, so it stays between the same lines as before. This means the other process's divider is lost,
+    and the content of both processes is packed together, without the blank space that was between them.
+    Text written with write_at() is moved along with its process, but the other process's blocks and settings are dropped.
+    If the two processes are incompatible, or either of them has bands (see set_bands()), it returns an error and gives the other process back unchanged.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut frame = grid::Frame::new(0, 0, 6, 4);
+    let mut grid = frame.next_frame();
+    let body = grid.split(&grid::SplitStrategy::new().max_y(3, grid::Alignment::Plus)).ok_or(())?;
+    let mut header = grid.into_process(grid::DividerStrategy::Beginning);
+    header.add_to_section("Title".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let mut body = body.into_process(grid::DividerStrategy::Beginning);
+    body.add_to_section("Body".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert!(header.merge(body).is_ok());
+    assert_eq!(header.height(), 4);
+    let lines: Vec<String> = header.rendered_lines().collect();
+    assert_eq!(lines, vec!["Title ", "Body  ", "      ", "      "]);
+    let incompatible = grid::Frame::new(2, 0, 4, 2).next_frame().into_process(grid::DividerStrategy::Beginning);
+    assert!(header.merge(incompatible).is_err());
+    # Ok(())
+    # }
+    ```
+    */
+    // The process is given back as-is, like extend() gives back its grid.
+    #[allow(clippy::result_large_err)]
+    pub fn merge(&mut self, mut other: DrawProcess) -> Result<(), DrawProcess> {
+        if self.start_x != other.start_x || self.end_x != other.end_x || !self.bands.is_empty() || !other.bands.is_empty() {
+            return Err(other);
+        }
+        if self.end_y == other.start_y {
+            let offset = self.height();
+            self.writes.extend(other.writes.drain(..).map(|(y, col, text)| (y + offset, col, text)));
+            other.minus.reverse();
+            self.plus.extend(other.minus);
+            self.plus.extend(other.plus);
+            self.end_y = other.end_y;
+        } else if self.start_y == other.end_y {
+            let offset = other.height();
+            for write in self.writes.iter_mut() {
+                write.0 += offset;
+            }
+            self.writes.append(&mut other.writes);
+            other.plus.reverse();
+            self.minus.extend(other.plus);
+            self.minus.extend(other.minus);
+            self.start_y = other.start_y;
+            self.divider += offset;
+        } else {
+            return Err(other);
+        }
+        self.invalidate();
+        Ok(())
+    }
+    #[doc(hidden)]
+    /// Moves the divider as little as possible so that both sections' content fits.
+    /// Assumes that the content of both sections fits in the process.
+    fn clamp_divider(&mut self) {
+        self.divider = self.divider.min(self.plus_end() - self.plus.len()).max(self.minus.len());
+    }
+    #[doc(hidden)]
+    /// Adds trimmed text to a section.
+    fn add_to_section_trimmed(&mut self, text: TrimmedText, section: Alignment) -> Result<(), InternalFormatError> {
+        if matches!(self.overflow, OverflowMode::DropOldest) {
+            // Makes room by removing the oldest line, which is the one next to the divider.
+            let capacity = self.capacity(section);
+            while capacity > 0 && self.section(section).len() >= capacity {
+                match section {
+                    Alignment::Minus => self.minus.remove(0),
+                    Alignment::Plus => self.plus.remove(0),
+                };
+                self.lines_removed(section, 0, 1);
+            }
+        }
+        if self.room(section) == 0 {
+            return Err(InternalFormatError::NoSpace(text));
+        }
+        self.cache_line(&text);
+        match section {
+            Alignment::Minus => self.minus.push(text),
+            Alignment::Plus => self.plus.push(text),
+        }
+        Ok(())
+    }
+    #[doc(hidden)]
+    /**
+    Shoves the data in the positive or negative direction, changing the divider to make more space available on one side.
+    Moving text to the bottom or top:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Ignore;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 10, 4).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Halfway);
+    process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
+    process.add_to_section("More stuff".to_string(), &mut Ignore, grid::Alignment::Minus);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("          \nMore stuff\nSome stuff\n          \n".to_string(), output);
+    process.shove(grid::Alignment::Minus);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("More stuff\nSome stuff\n          \n          \n".to_string(), output);
+    assert!(process.add_to_section("No room left".to_string(), &mut Ignore, grid::Alignment::Minus).is_err());
+    process.shove(grid::Alignment::Plus);
+    process.add_to_section("More room!".to_string(), &mut Ignore, grid::Alignment::Minus);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("          \nMore room!\nMore stuff\nSome stuff\n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    A section can have more lines than fit when it scrolls. Only the lines that fit are printed, wherever the divider is:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Halfway);
+    process.set_overflow(grid::OverflowMode::Scroll);
+    process.shove(grid::Alignment::Minus);
+    for line in ["One", "Two", "Three"].iter() {
+        process.add_to_section(line.to_string(), &mut Truncate, grid::Alignment::Minus).map_err(|_| ())?;
+    }
+    process.add_to_section("Plus".to_string(), &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+    process.shove(grid::Alignment::Minus);
+    process.shove(grid::Alignment::Plus);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Two  \nOne  \nPlus \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn shove(&mut self, direction: Alignment) {
+        match direction {
+            Alignment::Minus => self.divider = self.divider.min(self.minus.len()),
+            Alignment::Plus => self.divider = self.divider.max(self.plus_end().saturating_sub(self.plus.len())),
+        }
+    }
+    /**
+    Moves the divider to pos lines below the top of the process. Positions past the bottom of the process are moved to the bottom.
+    Moving the divider shrinks one of the sections. Any lines that no longer fit in it are removed from the outer edge of that section
+    and returned, in the order they were added, so that they can be added somewhere else.
+    With OverflowMode::Scroll, nothing is removed: the section keeps every line, and only shows as many as fit in it.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::{Truncate, TrimmedText};
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("One".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("Two".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("Three".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let removed = process.set_divider(2);
+    assert_eq!(removed, vec![TrimmedText::from("Two  ".to_string()), TrimmedText::from("Three".to_string())]);
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("     \n     \nOne  \n".to_string(), output);
+    assert!(process.set_divider(0).is_empty());
+    # Ok(())
+    # }
+    ```
+    Scrolling sections keep their lines:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.set_overflow(grid::OverflowMode::Scroll);
+    for line in ["1", "2", "3", "4", "5"].iter() {
+        process.add_to_section(line.to_string(), &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+    }
+    assert!(process.set_divider(1).is_empty());
+    assert_eq!(process.lines_used(grid::Alignment::Plus), 5);
+    assert_eq!(process.to_plain_lines(), vec!["1"]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_divider(&mut self, pos: usize) -> Vec<TrimmedText> {
+        let pos = pos.min(self.plus_end());
+        self.divider = pos;
+        if matches!(self.overflow, OverflowMode::Scroll) {
+            return Vec::new();
+        }
+        let minus_kept = self.minus.len().min(pos);
+        let plus_kept = self.plus.len().min(self.plus_end() - pos);
+        self.lines_removed(Alignment::Minus, minus_kept, self.minus.len() - minus_kept);
+        self.lines_removed(Alignment::Plus, plus_kept, self.plus.len() - plus_kept);
+        let mut removed = self.minus.split_off(minus_kept);
+        removed.append(&mut self.plus.split_off(plus_kept));
+        removed
+    }
+    /**
+    Shrinks the process by taking lines rows off one side of it, even if there's content there, and gives back the rows as a grid.
+    Alignment::Minus takes them off the top, and Alignment::Plus takes them off the bottom. No more than the whole process is taken.
+    The divider stays between the same rows if it can, and any content that no longer fits is removed like set_divider() removes it,
+    and given back in the order it was added, so that it can be added somewhere else. Text written with write_at() on the removed rows is dropped.
+    Like set_divider(), nothing is removed with OverflowMode::Scroll, so the content that doesn't fit can still be scrolled to.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::{Truncate, TrimmedText};
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 4).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    for line in ["One", "Two", "Three"].iter() {
+        process.add_to_section(line.to_string(), &mut Truncate, grid::Alignment::Plus);
+    }
+    let (freed, removed) = process.shrink(grid::Alignment::Plus, 2);
+    assert_eq!((freed.start_y, freed.end_y), (2, 4));
+    assert_eq!(removed, vec![TrimmedText::from("Three".to_string())]);
+    assert_eq!(process.to_plain_lines(), vec!["One", "Two"]);
+    let (freed, removed) = process.shrink(grid::Alignment::Minus, 1);
+    assert_eq!((freed.start_y, freed.end_y), (0, 1));
+    assert_eq!(removed, vec![TrimmedText::from("Two  ".to_string())]);
+    assert_eq!((process.start_y(), process.height()), (1, 1));
+    # Ok(())
+    # }
+    ```
+    Scrolling sections keep their lines:
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 4).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.set_overflow(grid::OverflowMode::Scroll);
+    for line in ["1", "2", "3", "4", "5", "6"].iter() {
+        process.add_to_section(line.to_string(), &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+    }
+    let (_, removed) = process.shrink(grid::Alignment::Plus, 2);
+    assert!(removed.is_empty());
+    assert_eq!(process.lines_used(grid::Alignment::Plus), 6);
+    process.scroll_to_bottom();
+    assert_eq!(process.to_plain_lines(), vec!["5", "6"]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn shrink(&mut self, a: Alignment, lines: usize) -> (Grid, Vec<TrimmedText>) {
+        let lines = lines.min(self.height());
+        let freed = match a {
+            Alignment::Minus => {
+                self.start_y += lines;
+                self.divider = self.divider.saturating_sub(lines);
+                for band in self.bands.iter_mut() {
+                    band.start = band.start.saturating_sub(lines);
                 }
-                let mut total_space = space.checked_sub(space_occupied).unwrap_or(0);
-                if let Some(val) = max_taken {
-                    total_space = total_space.min(val);
+                self.writes.retain(|(y, _, _)| *y >= lines);
+                for write in self.writes.iter_mut() {
+                    write.0 -= lines;
                 }
-                if total_space != 0 {
-                    self.start_y += total_space;
-                    Some(Grid {
-                        start_x: self.start_x,
-                        start_y: self.start_y - total_space,
-                        end_x: self.end_x,
-                        end_y: self.end_y - total_space,
-                    })
-                } else {
-                    None
+                Grid {
+                    start_x: self.start_x,
+                    start_y: self.start_y - lines,
+                    end_x: self.end_x,
+                    end_y: self.start_y,
+                }
+            }
+            Alignment::Plus => {
+                self.end_y -= lines;
+                let height = self.height();
+                self.writes.retain(|(y, _, _)| *y < height);
+                Grid {
+                    start_x: self.start_x,
+                    start_y: self.end_y,
+                    end_x: self.end_x,
+                    end_y: self.end_y + lines,
+                }
+            }
+        };
+        let mut removed = self.set_divider(self.divider);
+        for i in 0..self.bands.len() {
+            let height = self.band_end(i) - self.bands[i].start.min(self.height());
+            let lines = &mut self.bands[i].lines;
+            removed.extend(lines.split_off(height.min(lines.len())));
+        }
+        (freed, removed)
+    }
+    /// Gets where the divider is, as the number of rows between it and the top of the process.
+    /// For an example, see try_set_divider().
+    pub fn divider(&self) -> usize {
+        self.divider
+    }
+    /**
+    Moves the divider to pos lines below the top of the process, like set_divider(), but only if no content would be removed.
+    Content that's kept when scrolling (see set_overflow()) is never removed, so any position inside the process is fine then.
+    # Errors
+    If the position is past the bottom of the process, nothing is changed, and GeometryError::OutOfBounds is returned.
+    If either section would have more lines than fit on its side, nothing is changed, and GeometryError::Overflow is returned with the number of lines that wouldn't fit.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    assert_eq!(process.divider(), 0);
+    process.add_to_section("Plus".to_string(), &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+    assert_eq!(process.try_set_divider(1), Ok(()));
+    process.add_to_section("Top".to_string(), &mut Truncate, grid::Alignment::Minus).map_err(|_| ())?;
+    assert_eq!(process.divider(), 1);
+    let lines: Vec<String> = process.rendered_lines().collect();
+    assert_eq!(lines, vec!["Top  ", "Plus ", "     "]);
+    assert_eq!(process.try_set_divider(4), Err(grid::GeometryError::OutOfBounds));
+    assert_eq!(process.try_set_divider(0), Err(grid::GeometryError::Overflow(1)));
+    assert_eq!(process.try_set_divider(2), Ok(()));
+    let lines: Vec<String> = process.rendered_lines().collect();
+    assert_eq!(lines, vec!["     ", "Top  ", "Plus "]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn try_set_divider(&mut self, pos: usize) -> Result<(), GeometryError> {
+        if pos > self.plus_end() {
+            return Err(GeometryError::OutOfBounds);
+        }
+        let overflow = self.minus.len().saturating_sub(pos) + self.plus.len().saturating_sub(self.plus_end() - pos);
+        if overflow > 0 && !matches!(self.overflow, OverflowMode::Scroll) {
+            return Err(GeometryError::Overflow(overflow));
+        }
+        self.divider = pos;
+        Ok(())
+    }
+    /// Gets the most lines that a section can hold with the divider where it is, counting the lines already in it.
+    /// For an example, see reserve().
+    pub fn capacity(&self, section: Alignment) -> usize {
+        match section {
+            Alignment::Minus => self.divider.min(self.plus_end()),
+            Alignment::Plus => self.plus_end().saturating_sub(self.divider),
+        }
+    }
+    /**
+    Divides the process into any number of bands stacked from top to bottom, like a header, a body, and a footer.
+    Each divider is the number of rows between it and the top of the process, so n dividers make n + 1 bands.
+    The first divider is the process's divider, so band 0 is the minus section and band 1 is the plus section, and they work just like before.
+    Every divider after it starts another band below the plus section. Content is added to a band with add_to_band(),
+    and it's printed from the top of the band down. Dividers can be in the same place, which makes an empty band.
+    Giving just one divider turns the extra bands off again, like set_divider() with nothing removed.
+    # Errors
+    Returns GeometryError::OutOfBounds if there are no dividers, if they aren't in order from top to bottom, or if one is past the bottom
+    of the process. Returns GeometryError::Overflow with the number of lines that wouldn't fit if a band's content doesn't fit in it.
+    Either way, nothing is changed. Content that's kept when scrolling (see set_overflow()) always fits in the minus and plus sections.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 6, 5).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.set_bands(&[0, 1, 4]).map_err(|_| ())?;
+    assert_eq!(process.band_count(), 4);
+    process.add_to_band(1, "Title".to_string(), &mut Truncate).map_err(|_| ())?;
+    process.add_to_band(3, "Footer".to_string(), &mut Truncate).map_err(|_| ())?;
+    process.add_to_band(0, "Nope".to_string(), &mut Truncate).unwrap_err();
+    let lines: Vec<String> = process.rendered_lines().collect();
+    assert_eq!(lines, vec!["Title ", "      ", "      ", "      ", "Footer"]);
+    assert_eq!(process.set_bands(&[0, 1, 6]), Err(grid::GeometryError::OutOfBounds));
+    assert_eq!(process.set_bands(&[0, 0, 4]), Err(grid::GeometryError::Overflow(1)));
+    let mut grid = grid::Frame::new(0, 0, 6, 4).next_frame();
+    let mut scrolling = grid.into_process(grid::DividerStrategy::End);
+    scrolling.set_overflow(grid::OverflowMode::Scroll);
+    for line in ["1", "2", "3", "4"].iter() {
+        scrolling.add_to_section(line.to_string(), &mut Truncate, grid::Alignment::Minus).map_err(|_| ())?;
+    }
+    assert_eq!(scrolling.set_bands(&[2]), Ok(()));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn set_bands(&mut self, dividers: &[usize]) -> Result<(), GeometryError> {
+        let (&divider, starts) = dividers.split_first().ok_or(GeometryError::OutOfBounds)?;
+        let edges = dividers.iter().copied().chain(std::iter::once(self.height())).collect::<Vec<_>>();
+        if edges.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err(GeometryError::OutOfBounds);
+        }
+        let mut overflow = 0;
+        if !matches!(self.overflow, OverflowMode::Scroll) {
+            overflow += self.minus.len().saturating_sub(divider) + self.plus.len().saturating_sub(edges[1] - divider);
+        }
+        for (i, pair) in edges[1..].windows(2).enumerate() {
+            overflow += self.bands.get(i).map_or(0, |band| band.lines.len().saturating_sub(pair[1] - pair[0]));
+        }
+        // Bands that are going away have to be empty.
+        overflow += self.bands.iter().skip(starts.len()).map(|band| band.lines.len()).sum::<usize>();
+        if overflow > 0 {
+            return Err(GeometryError::Overflow(overflow));
+        }
+        self.divider = divider;
+        self.bands.truncate(starts.len());
+        for (i, &start) in starts.iter().enumerate() {
+            match self.bands.get_mut(i) {
+                Some(band) => band.start = start,
+                None => self.bands.push(Band { start, lines: Vec::new() }),
+            }
+        }
+        Ok(())
+    }
+    /// Gets how many bands the process has. Without set_bands(), there are two: the minus section and the plus section.
+    pub fn band_count(&self) -> usize {
+        self.bands.len() + 2
+    }
+    /**
+    Adds content to a band. Band 0 is the minus section and band 1 is the plus section, so adding to them is just like add_to_section().
+    Bands after that are filled from their top down. For an example, see set_bands().
+    # Errors
+    Returns an error if the content can't fit, like add_to_section(). The lines that fit are still added.
+    # Panics
+    Panics if the process doesn't have a band at the index.
+    */
+    pub fn add_to_band<T: TrimStrategy>(&mut self, index: usize, text: T::Input, strategy: &mut T) -> Result<(), FormatError<T>> {
+        match index {
+            0 => return self.add_to_section(text, strategy, Alignment::Minus),
+            1 => return self.add_to_section(text, strategy, Alignment::Plus),
+            _ => assert!(index < self.band_count(), "There's no band at index {}", index),
+        }
+        let i = index - 2;
+        let mut text = self.trim(text, strategy, Alignment::Plus);
+        let height = self.band_end(i) - self.bands[i].start.min(self.height());
+        let extras = text.split_off(text.len().min(height.saturating_sub(self.bands[i].lines.len())));
+        self.bands[i].lines.extend(text);
+        if extras.is_empty() {
+            Ok(())
+        } else {
+            Err(FormatError::NoSpace(strategy.back(extras, self, Alignment::Plus)))
+        }
+    }
+    #[doc(hidden)]
+    /// Gets the row that the plus section ends at, which is where the first band starts, or the bottom of the process if there aren't any.
+    fn plus_end(&self) -> usize {
+        self.bands.first().map_or(self.height(), |band| band.start.min(self.height()))
+    }
+    #[doc(hidden)]
+    /// Gets the row that a band below the plus section ends at, which is where the next one starts, or the bottom of the process.
+    fn band_end(&self, i: usize) -> usize {
+        self.bands.get(i + 1).map_or(self.height(), |band| band.start.min(self.height()))
+    }
+    #[doc(hidden)]
+    /// Counts the lines that wouldn't fit if the process had the given height, with its bands below the plus section starting at the given rows.
+    /// The divider can move, so the minus and plus sections only have to fit above the first band together.
+    fn overflow_with_bands(&self, starts: &[usize], height: usize) -> usize {
+        let ends = starts.iter().skip(1).copied().chain(std::iter::once(height));
+        let sections = (self.minus.len() + self.plus.len()).saturating_sub(starts.first().copied().unwrap_or(height));
+        let bands = self.bands.iter().zip(starts.iter().zip(ends)).map(|(band, (start, end))| band.lines.len().saturating_sub(end - start));
+        sections + bands.sum::<usize>()
+    }
+    #[doc(hidden)]
+    /// Gets every stored line of the process, in each section and band.
+    fn lines_mut(&mut self) -> impl Iterator<Item = &mut TrimmedText> {
+        self.minus.iter_mut().chain(self.plus.iter_mut()).chain(self.bands.iter_mut().flat_map(|band| band.lines.iter_mut()))
+    }
+    /**
+    Makes sure that at least lines more lines can be added to a section, moving the divider into the other section's free space if it has to.
+    Content is never removed: the other section only gives up rows that are blank.
+    Moving the divider moves the content next to it, so content that's already been added may be printed on different rows afterwards.
+    # Errors
+    If the other section doesn't have enough free space, nothing is changed, and the number of lines that couldn't be reserved is returned.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 4).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Plus".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(process.capacity(grid::Alignment::Minus), 0);
+    assert_eq!(process.reserve(grid::Alignment::Minus, 2), Ok(()));
+    assert_eq!(process.capacity(grid::Alignment::Minus), 2);
+    process.add_to_section("Minus".to_string(), &mut Truncate, grid::Alignment::Minus);
+    // The plus section has one free row, and the minus section can only give up one more.
+    assert_eq!(process.reserve(grid::Alignment::Plus, 3), Err(1));
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("     \nMinus\nPlus \n     \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn reserve(&mut self, section: Alignment, lines: usize) -> Result<(), usize> {
+        let needed = lines.saturating_sub(self.free_lines(section));
+        let available = match section {
+            Alignment::Minus => self.free_lines(Alignment::Plus),
+            Alignment::Plus => self.free_lines(Alignment::Minus),
+        };
+        if needed > available {
+            return Err(needed - available);
+        }
+        match section {
+            Alignment::Minus => self.divider += needed,
+            Alignment::Plus => self.divider -= needed,
+        }
+        Ok(())
+    }
+    #[doc(hidden)]
+    /// Lays out the process, finding what goes on each row from top to bottom.
+    fn rows(&self) -> Vec<Row<'_>> {
+        let mut result = Vec::new();
+        // Hidden sections that are collapsed don't take up any rows, and the divider moves out of the way.
+        let collapsed = |hidden: bool| hidden && self.collapse_hidden;
+        let minus: &[TrimmedText] = if collapsed(self.hidden_minus) { &[] } else { &self.minus };
+        let plus: &[TrimmedText] = if collapsed(self.hidden_plus) { &[] } else { &self.plus };
+        let divider = match (collapsed(self.hidden_minus), collapsed(self.hidden_plus)) {
+            (true, false) => 0,
+            (false, true) => self.plus_end(),
+            _ => self.divider,
+        }
+        .min(self.plus_end());
+        // Only the lines inside each section's scroll window are shown.
+        let (minus_start, minus) = scroll_window(minus, self.scroll_minus, divider);
+        let (plus_start, plus) = scroll_window(plus, self.scroll_plus, self.plus_end() - divider);
+        // Splits each section's blank rows into the ones before and after its content.
+        let minus_blank = divider - minus.len();
+        let plus_blank = self.plus_end() - divider - plus.len();
+        let (minus_before, plus_before) = match self.v_align {
+            VAlign::Divider => (minus_blank, 0),
+            VAlign::Center => (minus_blank / 2, plus_blank / 2),
+            VAlign::Edge => (0, plus_blank),
+        };
+        // Adds blank lines, making sure that the entirety of grid is clear.
+        result.extend(std::iter::repeat(Row::Blank).take(minus_before));
+        // Adds negative lines
+        for (i, line) in minus.iter().enumerate().rev() {
+            result.push(if self.hidden_minus { Row::Blank } else { Row::Content(Alignment::Minus, minus_start + i, line) });
+        }
+        result.extend(std::iter::repeat(Row::Blank).take(minus_blank - minus_before + plus_before));
+        // Adds positive lines
+        for (i, line) in plus.iter().enumerate() {
+            result.push(if self.hidden_plus { Row::Blank } else { Row::Content(Alignment::Plus, plus_start + i, line) });
+        }
+        // Adds blank lines, making sure that the entirety of grid is clear.
+        result.extend(std::iter::repeat(Row::Blank).take(plus_blank - plus_before));
+        // Adds the bands below the plus section, each filled out with blank rows to where the next one starts.
+        for (i, band) in self.bands.iter().enumerate() {
+            let height = self.band_end(i) - band.start.min(self.height());
+            result.extend(band.lines.iter().take(height).map(Row::Band));
+            result.extend(std::iter::repeat(Row::Blank).take(height.saturating_sub(band.lines.len())));
+        }
+        if let Some(shadow) = self.divider_shadow {
+            if let Some(row) = result.iter_mut().skip(divider).find(|row| matches!(row, Row::Blank)) {
+                *row = Row::Shadow(shadow);
+            }
+        }
+        if self.collapse_blanks {
+            let empty = |row: &Row| matches!(row, Row::Content(_, _, line) if line.unpadded().is_empty());
+            result.dedup_by(|row, previous| empty(row) && empty(previous));
+            result.resize(self.height(), Row::Blank);
+        }
+        result
+    }
+    #[doc(hidden)]
+    /// Gets the text printed for a row, given its index from the top of the process.
+    fn row_text<'a>(&'a self, index: usize, row: &Row<'a>) -> Cow<'a, str> {
+        let text = match row {
+            Row::Blank => self.blank_text(),
+            Row::Shadow(c) => Cow::Owned(std::iter::repeat(*c).take(self.width()).collect()),
+            Row::Band(line) => self.align_line(line),
+            Row::Content(section, stored, line) => {
+                let text = match self.line_cache.0.as_ref().and_then(|cache| cache.get(line.unpadded())) {
+                    Some(cached) => Cow::Borrowed(cached.as_str()),
+                    None => self.align_line(line),
+                };
+                self.number_line(*section, *stored, text)
+            }
+        };
+        let text = self.apply_writes(index, text);
+        match self.direction {
+            TextDirection::LeftToRight => text,
+            TextDirection::RightToLeft => Cow::Owned(text.graphemes(true).rev().collect()),
+        }
+    }
+    #[doc(hidden)]
+    /// Puts the line number in the gutter at the start of a line of content, if there's a gutter.
+    fn number_line<'a>(&self, section: Alignment, stored: usize, text: Cow<'a, str>) -> Cow<'a, str> {
+        if self.gutter == 0 {
+            return text;
+        }
+        // Lines are numbered by where they're stored, so the numbers don't depend on which rows they're printed on.
+        let number = match section {
+            Alignment::Minus => self.minus.len() - stored,
+            Alignment::Plus => self.minus.len() + stored + 1,
+        };
+        let digits = self.gutter - 1;
+        let number = number.to_string();
+        // Numbers that are too long for the gutter only show their last digits.
+        let number = &number[number.len().saturating_sub(digits)..];
+        let mut res = format!("{:>width$}", number, width = digits);
+        if self.gutter <= self.width() {
+            res.push(' ');
+        }
+        res.push_str(cut(&text, self.width().saturating_sub(self.gutter)));
+        Cow::Owned(cut(&res, self.width()).to_string())
+    }
+    #[doc(hidden)]
+    /// Whether anything was written onto a row with write_at().
+    fn is_written(&self, index: usize) -> bool {
+        self.writes.iter().any(|(y, _, _)| *y == index)
+    }
+    #[doc(hidden)]
+    /// Puts the text written with write_at() on top of a row's text, in the order it was written.
+    fn apply_writes<'a>(&'a self, index: usize, text: Cow<'a, str>) -> Cow<'a, str> {
+        if !self.is_written(index) {
+            return text;
+        }
+        // Each cell is one column. A wide grapheme is kept in its first cell, and the other cells it covers are empty.
+        let blank = self.blank_text();
+        let mut cells = Vec::new();
+        for grapheme in text.graphemes(true) {
+            cells.push(grapheme.to_string());
+            cells.extend(std::iter::repeat(String::new()).take(text_width(grapheme).saturating_sub(1)));
+        }
+        for (_, col, written) in self.writes.iter().filter(|(y, _, _)| *y == index) {
+            let mut col = *col;
+            for grapheme in written.graphemes(true) {
+                let width = text_width(grapheme).max(1);
+                // Rows that are too short are filled out with the blank space a blank row would have there.
+                while cells.len() < col + width {
+                    cells.push(blank.graphemes(true).nth(cells.len()).unwrap_or(" ").to_string());
+                }
+                // Wide graphemes that are partly covered are replaced by blank space.
+                let mut start = col;
+                while start > 0 && cells[start].is_empty() {
+                    start -= 1;
+                }
+                let mut end = col + width;
+                while end < cells.len() && cells[end].is_empty() {
+                    end += 1;
+                }
+                for cell in &mut cells[start..end] {
+                    *cell = " ".to_string();
+                }
+                cells[col] = grapheme.to_string();
+                for cell in &mut cells[col + 1..col + width] {
+                    cell.clear();
+                }
+                col += width;
+            }
+        }
+        Cow::Owned(cells.concat())
+    }
+    #[doc(hidden)]
+    /// Positions a line horizontally, according to the horizontal alignment.
+    /// Left-aligned lines are printed exactly as they were trimmed.
+    fn align_line<'a>(&'a self, line: &'a TrimmedText) -> Cow<'a, str> {
+        if matches!(self.h_align, HAlign::Left) {
+            return Cow::Borrowed(&line.0);
+        }
+        let text = line.unpadded();
+        let len = text_width(text);
+        let before = self.align_offset(len);
+        // The padding comes from the same positions of a blank line, so that it matches the blank rows around it.
+        let blank = self.blank_text();
+        let mut res = blank.chars().take(before).collect::<String>();
+        res.push_str(text);
+        res.extend(blank.chars().skip(before + len));
+        Cow::Owned(res)
+    }
+    #[doc(hidden)]
+    /// Gets the blank space of a row, in the order that the row's text is built in.
+    /// Right to left rows are reversed once they're built, so their blank space is reversed here, which puts the fill pattern back in place.
+    fn blank_text(&self) -> Cow<'_, str> {
+        match self.direction {
+            TextDirection::LeftToRight => Cow::Borrowed(&self.example_str),
+            TextDirection::RightToLeft => Cow::Owned(self.example_str.graphemes(true).rev().collect()),
+        }
+    }
+    #[doc(hidden)]
+    /// Gets how many columns come before a line's text of a width, according to the horizontal alignment.
+    fn align_offset(&self, len: usize) -> usize {
+        let space = self.width().saturating_sub(len);
+        match self.h_align {
+            HAlign::Left => 0,
+            HAlign::Center => match self.center_bias {
+                CenterBias::Left => space - space / 2,
+                CenterBias::Right => space / 2,
+            },
+            HAlign::Right => space,
+        }
+    }
+    #[doc(hidden)]
+    /// Gets the color that a stored line is printed in, if it has one.
+    fn color_at(&self, section: Alignment, index: usize) -> Option<Color> {
+        self.blocks
+            .iter()
+            .filter(|block| block.section == section && block.start <= index && index < block.start + block.len)
+            .find_map(|block| block.color)
+    }
+    #[doc(hidden)]
+    /// Gets the uri that a stored line links to, if it links to anything.
+    fn link_at(&self, section: Alignment, index: usize) -> Option<&str> {
+        self.blocks
+            .iter()
+            .filter(|block| block.section == section && block.start <= index && index < block.start + block.len)
+            .find_map(|block| block.link.as_deref())
+    }
+    #[doc(hidden)]
+    /// Transforms the board into actions, sending each one to the function in order.
+    fn grab_actions<E, F: FnMut(&Action) -> Result<(), E>>(&self, f: F) -> Result<(), E> {
+        self.grab_row_actions(&self.rows(), f)
+    }
+    #[doc(hidden)]
+    /// Transforms a layout of the board into actions, sending each one to the function in order.
+    fn grab_row_actions<E, F: FnMut(&Action) -> Result<(), E>>(&self, rows: &[Row], f: F) -> Result<(), E> {
+        self.grab_some_rows(rows, 0..rows.len(), f)
+    }
+    #[doc(hidden)]
+    /// Transforms some of the rows of a layout into actions, given their indexes in order, sending each action to the function in order.
+    fn grab_some_rows<E, F, I>(&self, rows: &[Row], indexes: I, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&Action) -> Result<(), E>,
+        I: Iterator<Item = usize>,
+    {
+        if self.sync_updates {
+            f(&Action::BeginSyncUpdate)?;
+        }
+        let content = content_rows(rows).collect::<Vec<_>>();
+        for i in indexes {
+            self.grab_row(i, &rows[i], content[i], &mut f)?;
+        }
+        if self.sync_updates {
+            f(&Action::EndSyncUpdate)?;
+        }
+        Ok(())
+    }
+    #[doc(hidden)]
+    /// Transforms a single row into actions, given its index from the top and how many rows of content are above it.
+    fn grab_row<E, F: FnMut(&Action) -> Result<(), E>>(&self, i: usize, row: &Row, content_row: usize, f: &mut F) -> Result<(), E> {
+        f(&Action::MoveTo(self.start_x, self.start_y + i))?;
+        match row {
+            Row::Blank if !self.is_written(i) && self.fill_pattern.chars().count() == 1 => f(&Action::Repeat(self.fill_char, self.width()))?,
+            Row::Shadow(c) if !self.is_written(i) => f(&Action::Repeat(*c, self.width()))?,
+            Row::Content(section, index, _) => {
+                let stripe = self.stripe(content_row);
+                let color = self.color_at(*section, *index);
+                if let Some(color) = stripe {
+                    f(&Action::SetBackground(color))?;
                 }
-            },
-            Alignment::Plus => {
-                println!("END: {} START: {} DIVIDE: {}", self.end_y, self.start_y, self.divider);
-                let space = self.end_y - self.start_y - self.divider;
-                println!("SPACE: {}", space);
-                let mut space_occupied = self.plus.len();
-                println!("OCCUPIED: {}", space_occupied);
-                if let Some(val) = min_left {
-                    space_occupied = space_occupied.max(val);
+                if let Some(color) = color {
+                    f(&Action::SetColor(color))?;
                 }
-                let mut total_space = space.checked_sub(space_occupied).unwrap_or(0);
-                println!("TOTAL: {}", total_space);
-                if let Some(val) = max_taken {
-                    total_space = total_space.min(val);
+                match self.link_at(*section, *index) {
+                    Some(uri) => f(&Action::Hyperlink { uri, text: &self.row_text(i, row) })?,
+                    None => f(&Action::Print(&self.row_text(i, row)))?,
                 }
-                if total_space != 0 {
-                    self.end_y -= total_space;
-                    Some(Grid {
-                        start_x: self.start_x,
-                        start_y: self.end_y,
-                        end_x: self.end_x,
-                        end_y: self.end_y + total_space,
-                    })
-                } else {
-                    None
+                if stripe.is_some() || color.is_some() {
+                    f(&Action::ResetColor)?;
                 }
-            },
+            }
+            _ => f(&Action::Print(&self.row_text(i, row)))?,
+        }
+        Ok(())
+    }
+    #[doc(hidden)]
+    /// Gets the background of a row of content, given how many rows of content are above it.
+    /// Stripes alternate with each row of content, skipping over blank rows.
+    fn stripe(&self, content_row: usize) -> Option<Color> {
+        self.zebra.map(|(even, odd)| if content_row % 2 == 0 { even } else { odd })
+    }
+    #[doc(hidden)]
+    /// Gets everything that decides what a row looks like when it's printed, so that rows can be compared.
+    fn printed_row(&self, i: usize, row: &Row, content_row: usize) -> PrintedRow {
+        let (color, background, link) = match row {
+            Row::Content(section, index, _) => (
+                self.color_at(*section, *index),
+                self.stripe(content_row),
+                self.link_at(*section, *index).map(str::to_string),
+            ),
+            _ => (None, None, None),
+        };
+        PrintedRow {
+            text: self.row_text(i, row).into_owned(),
+            color,
+            background,
+            link,
         }
     }
     /**
-    Extends the grid in the either direction, either positive or negative, if the input is compatible
-    (ie grids are next to each other and of similar dimensions)
-    If the two grids are incompatible, it returns an error and gives the grid back. 
-    # Example
+    Prints out the grid using a handler. The process is only borrowed, so it can be printed to several handlers in a row.
+    (print_diff() is the one way of printing that changes the process, since it remembers what was printed.)
+    # Errors
+    Returns an error if the handler returns an error.
     ``` rust
     # use grid_ui::grid;
     # use grid_ui::out;
     # use grid_ui::trim::Ignore;
     # fn main() -> Result<(), ()>{
-    let mut grid = grid::Frame::new(0, 0, 10, 10).next_frame();
+    let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
     let mut process = grid.into_process(grid::DividerStrategy::Beginning);
     process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
-    let free_space = process.split_free_space(grid::Alignment::Plus, None, None).ok_or(())?;
-    assert_eq!(process.end_y(), 1);
-    assert!(process.extend(free_space).is_ok());
-    assert_eq!(process.end_y(), 10);
-    let incompatible_grid = grid::Frame::new(4, 4, 8, 8).next_frame();
-    assert!(process.extend(incompatible_grid).is_err());
+    let mut output: String = String::new();
+    process.print(&mut out::OutToString, &mut output)?;
+    assert_eq!("Some stuff\n          \n          \n".to_string(), output);
     # Ok(())
     # }
     ```
     */
-    pub fn extend(&mut self, grid: Grid) -> Result<(), Grid> {
-        if self.start_x == grid.start_x && self.end_x == grid.end_x {
-            if self.end_y == grid.start_y {
-                self.end_y = grid.end_y;
-                return Ok(())
-            }
-            if self.start_y == grid.end_y {
-                self.start_y = grid.start_y;
-                return Ok(())
-            }
+    pub fn print<H: Handler>(&self, handler: &mut H, out: &mut H::OutputDevice) -> Result<(), H::Error> {
+        self.grab_actions(|action| handler.handle(out, action))
+    }
+    /**
+    Prints out the grid using a handler, like print(), but only prints the rows that changed since the last time print_diff() was called.
+    The first call prints everything, and so does any call after the process has been moved or resized.
+    Other ways of printing don't count, so if something else draws over the process, the next call might skip rows that need to be redrawn.
+    # Errors
+    Returns an error if the handler returns an error. The rows are still counted as printed.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::{Action, SafeHandler};
+    # use grid_ui::trim::Truncate;
+    struct Count(usize);
+    impl SafeHandler for Count {
+        type OutputDevice = ();
+        fn safe_handle(&mut self, _: &mut (), input: &Action) {
+            self.0 += 1;
         }
-        Err(grid)
     }
-    #[doc(hidden)]
-    /// Adds trimmed text to a section.
-    fn add_to_section_trimmed(&mut self, text: TrimmedText, section: Alignment) -> Result<(), InternalFormatError> {
-        if matches!(section, Alignment::Minus) {
-            let space = self.divider - self.minus.len();
-            if space == 0 {
-                return Err(InternalFormatError::NoSpace(text));
-            }
-            self.minus.push(text);
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("One".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let mut count = Count(0);
+    process.print_diff(&mut count, &mut ())?;
+    assert_eq!(count.0, 6); // A MoveTo and a Print (or Repeat) for every row.
+    count.0 = 0;
+    process.print_diff(&mut count, &mut ())?;
+    assert_eq!(count.0, 0);
+    process.add_to_section("Two".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.print_diff(&mut count, &mut ())?;
+    assert_eq!(count.0, 2); // Only the new row is printed.
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn print_diff<H: Handler>(&mut self, handler: &mut H, out: &mut H::OutputDevice) -> Result<(), H::Error> {
+        let rows = self.rows();
+        let content = content_rows(&rows).collect::<Vec<_>>();
+        let printed = rows.iter().enumerate().map(|(i, row)| self.printed_row(i, row, content[i])).collect::<Vec<_>>();
+        let last = match &self.printed.0 {
+            Some((bounds, last)) if *bounds == self.bounds() => last.as_slice(),
+            _ => &[],
+        };
+        let changed = (0..rows.len()).filter(|&i| last.get(i) != Some(&printed[i])).collect::<Vec<_>>();
+        let result = if changed.is_empty() {
+            Ok(())
         } else {
-            let space = self.end_y - self.start_y - self.divider - self.plus.len();
-            if space == 0 {
-                return Err(InternalFormatError::NoSpace(text));
+            self.grab_some_rows(&rows, changed.into_iter(), |action| handler.handle(out, action))
+        };
+        self.printed = Snapshot(Some((self.bounds(), printed)));
+        result
+    }
+    /**
+    Calls a closure with every action that printing the process would send to a handler, in order.
+    This is a quick way to look at the actions without writing a handler. The process is only borrowed,
+    and the strings inside each action are only borrowed for the duration of the call, so copy them if you need to keep them.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::Action;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Hi".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let mut printed = Vec::new();
+    process.for_each_action(|action| if let Action::Print(text) = action {
+        printed.push(text.to_string());
+    });
+    assert_eq!(printed, vec!["Hi   ".to_string()]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn for_each_action<F: FnMut(&Action)>(&self, mut f: F) {
+        let _: Result<(), Infallible> = self.grab_actions(|action| {
+            f(action);
+            Ok(())
+        });
+    }
+    /**
+    Gets every action that printing the process would send to a handler, in order, as recorded actions that own their text.
+    Most rows' text is made while printing, so it can't be borrowed from the process, which is why the actions are recorded.
+    The order is always the same: if sync updates are on, BeginSyncUpdate comes first and EndSyncUpdate last.
+    In between, each row is printed from top to bottom, starting with a MoveTo to its left edge.
+    A row of content that has colors is surrounded by SetBackground and SetColor (in that order) and ResetColor.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::{Color, RecordedAction};
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(1, 1, 4, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_colored_to_section("Hi".to_string(), Color::Red, &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+    assert_eq!(
+        process.actions(),
+        vec![
+            RecordedAction::MoveTo(1, 1),
+            RecordedAction::SetColor(Color::Red),
+            RecordedAction::Print("Hi ".to_string()),
+            RecordedAction::ResetColor,
+            RecordedAction::MoveTo(1, 2),
+            RecordedAction::Repeat(' ', 3),
+        ]
+    );
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn actions(&self) -> Vec<RecordedAction> {
+        let mut res = Vec::new();
+        self.for_each_action(|action| res.push(action.into()));
+        res
+    }
+    /**
+    Gets the actions that printing the process would send to a handler, like actions(), but only the parts that are inside a viewport.
+    Text that's entirely outside of the viewport is dropped, along with the moves before it, and text that's partly inside is cut down
+    to the part that's inside, with a move to wherever that part starts. This way, a tall or wide process can be shown through a smaller
+    viewport, like a scrolling container, without drawing over what's around it. Colors, links, and every other action are kept as they are.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::RecordedAction;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 6, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    for line in ["Line 1", "Line 2", "Line 3"].iter() {
+        process.add_to_section(line.to_string(), &mut Truncate, grid::Alignment::Plus);
+    }
+    let viewport = grid::Grid {start_x: 2, start_y: 1, end_x: 10, end_y: 3};
+    assert_eq!(
+        process.actions_clipped(viewport),
+        vec![
+            RecordedAction::MoveTo(2, 1),
+            RecordedAction::Print("ne 2".to_string()),
+            RecordedAction::MoveTo(2, 2),
+            RecordedAction::Print("ne 3".to_string()),
+        ]
+    );
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn actions_clipped(&self, viewport: Grid) -> Vec<RecordedAction> {
+        let mut res = Vec::new();
+        // Where the process's actions would leave the cursor, and where the clipped actions leave it, if that's known.
+        let mut position = (0, 0);
+        let mut clipped_position = None;
+        self.for_each_action(|action| {
+            let text: Cow<str> = match action {
+                Action::Print(text) | Action::Hyperlink { text, .. } => Cow::Borrowed(text),
+                Action::Repeat(c, n) => Cow::Owned(std::iter::repeat(*c).take(*n).collect()),
+                Action::MoveTo(_, _) | Action::MoveBy(_, _) => {
+                    advance(&mut position, action);
+                    return;
+                }
+                _ => {
+                    res.push(action.into());
+                    return;
+                }
+            };
+            let (x, y) = position;
+            advance(&mut position, action);
+            if y < viewport.start_y || y >= viewport.end_y {
+                return;
             }
-            self.plus.push(text);
-        }
-        Ok(())
+            let start = viewport.start_x.max(x);
+            let end = viewport.end_x.min(x + text_width(&text));
+            if start >= end {
+                return;
+            }
+            // A wide character that's cut by the left edge is left out, and the text starts after it.
+            let (rest, skipped) = skip_columns(&text, start - x);
+            let start = x + skipped;
+            let visible = cut(rest, end.saturating_sub(start));
+            if visible.is_empty() {
+                return;
+            }
+            if clipped_position != Some((start, y)) {
+                res.push(RecordedAction::MoveTo(start, y));
+            }
+            clipped_position = Some((start + text_width(visible), y));
+            let visible = visible.to_string();
+            res.push(match action {
+                Action::Repeat(c, _) => RecordedAction::Repeat(*c, visible.chars().count()),
+                Action::Hyperlink { uri, .. } => RecordedAction::Hyperlink {
+                    uri: uri.to_string(),
+                    text: visible,
+                },
+                _ => RecordedAction::Print(visible),
+            });
+        });
+        res
     }
-    #[doc(hidden)]
     /**
-    Shoves the data in the positive or negative direction, changing the divider to make more space available on one side.
-    Moving text to the bottom or top:
+    Gets every row of the process from top to bottom, exactly as printing it would draw them, including blank rows and padding.
+    This is the text of the actions, without the moves, colors, or links.
+    # Example
     ``` rust
     # use grid_ui::grid;
-    # use grid_ui::out;
-    # use grid_ui::trim::Ignore;
+    # use grid_ui::trim::Truncate;
     # fn main() -> Result<(), ()>{
-    let mut grid = grid::Frame::new(0, 0, 10, 4).next_frame();
+    let mut grid = grid::Frame::new(0, 0, 5, 4).next_frame();
     let mut process = grid.into_process(grid::DividerStrategy::Halfway);
-    process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
-    process.add_to_section("More stuff".to_string(), &mut Ignore, grid::Alignment::Minus);
-    let mut output: String = String::new();
-    process.print(&mut out::OutToString, &mut output)?;
-    assert_eq!("          \nMore stuff\nSome stuff\n          \n".to_string(), output);
-    process.shove(grid::Alignment::Minus);
-    let mut output: String = String::new();
-    process.print(&mut out::OutToString, &mut output)?;
-    assert_eq!("More stuff\nSome stuff\n          \n          \n".to_string(), output);
-    assert!(process.add_to_section("No room left".to_string(), &mut Ignore, grid::Alignment::Minus).is_err());
-    process.shove(grid::Alignment::Plus);
-    process.add_to_section("More room!".to_string(), &mut Ignore, grid::Alignment::Minus);
-    let mut output: String = String::new();
-    process.print(&mut out::OutToString, &mut output)?;
-    assert_eq!("          \nMore room!\nMore stuff\nSome stuff\n".to_string(), output);
+    process.add_to_section("One".to_string(), &mut Truncate, grid::Alignment::Minus);
+    process.add_to_section("Two".to_string(), &mut Truncate, grid::Alignment::Minus);
+    process.add_to_section("Three".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let lines: Vec<String> = process.rendered_lines().collect();
+    assert_eq!(lines, vec!["Two  ", "One  ", "Three", "     "]);
     # Ok(())
     # }
     ```
     */
-    pub fn shove(&mut self, direction: Alignment) {
-        match direction {
-            Alignment::Minus => self.divider = self.divider.min(self.minus.len()),
-            Alignment::Plus => self.divider = self.divider.max(self.end_y - self.start_y - self.plus.len()),
-        }
+    pub fn rendered_lines(&self) -> impl Iterator<Item = String> + '_ {
+        self.rows().into_iter().enumerate().map(move |(i, row)| self.row_text(i, &row).into_owned())
     }
-    #[doc(hidden)]
-    /// Transforms the board into actions.
-    fn grab_actions(&mut self) -> Vec<Action> {
-        let mut result = Vec::new();
-        let start_x = self.start_x;
-        let start_y = self.start_y + self.divider - self.minus.len();
-        let divider = self.start_y + self.divider;
-        // Adds blank lines, making sure that the entirety of grid is clear.
-        for i in self.start_y..start_y {
-            result.push(Action::MoveTo(start_x, i));
-            result.push(Action::Print(&self.example_str));
-        }
-        // Adds negative lines
-        for (i, line) in self.minus.iter().rev().enumerate() {
-            result.push(Action::MoveTo(start_x, start_y + i));
-            result.push(Action::Print(&line.0));
-        }
-        // Adds positive lines
-        for (i, line) in self.plus.iter().enumerate() {
-            result.push(Action::MoveTo(start_x, divider + i));
-            result.push(Action::Print(&line.0));
-        }
-        // Adds blank lines, making sure that the entirety of grid is clear.
-        for i in self.start_y + self.divider + self.plus.len()..self.end_y {
-            result.push(Action::MoveTo(start_x, i));
-            result.push(Action::Print(&self.example_str));
-        }
-        result
+    /**
+    Renders the process into a grid of characters, height() rows of width() characters each, so it can be copied onto a larger buffer.
+    The rows are the same as rendered_lines() gives: the divider, the order of both sections, and horizontal alignment are all followed.
+    Rows that are too short (like ones added with Ignore) are filled in with the fill character, and rows that are too long are cut off.
+    Each character takes up one cell, so text with wide or combining characters won't line up with how a terminal would draw it.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::trim::Ignore;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 3, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Halfway);
+    process.set_fill_char('.');
+    process.add_to_section("a".to_string(), &mut Ignore, grid::Alignment::Minus);
+    process.add_to_section("bc".to_string(), &mut Truncate, grid::Alignment::Plus);
+    process.add_to_section("long".to_string(), &mut Ignore, grid::Alignment::Plus);
+    let cells = process.render_to_cells();
+    assert_eq!(cells, vec![vec!['a', '.', '.'], vec!['b', 'c', ' '], vec!['l', 'o', 'n']]);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn render_to_cells(&self) -> Vec<Vec<char>> {
+        let width = self.width();
+        self.rendered_lines()
+            .map(|line| {
+                let mut cells = line.chars().take(width).collect::<Vec<_>>();
+                let filled = cells.len();
+                cells.extend(self.example_str.chars().skip(filled));
+                cells.resize(width, self.fill_char);
+                cells
+            })
+            .collect()
     }
     /**
-    Prints out the grid using a handler.
+    Prints out the grid using a handler, like print(), and returns where the cursor ends up.
+    That's the position after the last action: the location of the last MoveTo, moved right by the width of everything printed after it.
+    A process with no rows leaves the cursor at its top left corner.
     # Errors
     Returns an error if the handler returns an error.
+    # Example
     ``` rust
     # use grid_ui::grid;
     # use grid_ui::out;
-    # use grid_ui::trim::Ignore;
+    # use grid_ui::trim::{Ignore, Truncate};
     # fn main() -> Result<(), ()>{
-    let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+    let mut grid = grid::Frame::new(2, 1, 10, 3).next_frame();
     let mut process = grid.into_process(grid::DividerStrategy::Beginning);
     process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus);
     let mut output: String = String::new();
-    process.print(&mut out::OutToString, &mut output)?;
-    assert_eq!("Some stuff\n          \n          \n".to_string(), output);
+    assert_eq!(process.print_tracking(&mut out::OutToString, &mut output)?, (10, 2));
+    # #[cfg(feature = "unicode-width")] {
+    let mut process = grid::Frame::new(0, 0, 6, 1).next_frame().into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("日本".to_string(), &mut Truncate, grid::Alignment::Plus);
+    assert_eq!(process.print_tracking(&mut out::OutToString, &mut output)?, (6, 0));
+    # }
     # Ok(())
     # }
     ```
     */
-    pub fn print<H: Handler>(&mut self, handler: &mut H, out: &mut H::OutputDevice) -> Result<(), H::Error> {
-        let actions = self.grab_actions();
-        for line in actions {
-            handler.handle(out, &line)?;
-        }
-        Ok(())
+    pub fn print_tracking<H: Handler>(&self, handler: &mut H, out: &mut H::OutputDevice) -> Result<(usize, usize), H::Error> {
+        let mut position = (self.start_x, self.start_y);
+        self.grab_actions(|action| {
+            advance(&mut position, action);
+            handler.handle(out, action)
+        })?;
+        Ok(position)
+    }
+    /**
+    Prints out the grid using a handler, like print_tracking(), but leaves out every MoveTo to where the cursor already is.
+    It's given where the cursor is to begin with, which is usually what the last call to this (or print_tracking()) gave back.
+    This assumes that the handler moves the cursor right past all the text it prints, like a terminal does.
+    Every row of a process starts at its left edge, so this saves the most when processes are printed one after another,
+    and each one starts where the last one ended, like the pieces of a status bar.
+    # Errors
+    Returns an error if the handler returns an error.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::{RecordedAction, RecordingHandler};
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut left = grid::Frame::new(0, 0, 6, 1).next_frame();
+    let right = left.split(&grid::SplitStrategy::new().max_x(3, grid::Alignment::Plus)).ok_or(())?;
+    let mut left = left.into_process(grid::DividerStrategy::Beginning);
+    let mut right = right.into_process(grid::DividerStrategy::Beginning);
+    left.add_to_section("ab".to_string(), &mut Truncate, grid::Alignment::Plus);
+    right.add_to_section("cd".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let mut actions = Vec::new();
+    let cursor = left.print_optimized(&mut RecordingHandler, &mut actions, (0, 0))?;
+    let cursor = right.print_optimized(&mut RecordingHandler, &mut actions, cursor)?;
+    assert_eq!(cursor, (6, 0));
+    assert_eq!(actions, vec![RecordedAction::Print("ab ".to_string()), RecordedAction::Print("cd ".to_string())]);
+    # Ok(())
+    # }
+    ```
+    The cursor is followed through every action, so a whole bar of processes only has to move it once. Each row after the first
+    still starts with a MoveTo, since the cursor ends up on the right of the row above it.
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out::{RecordedAction, RecordingHandler};
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut frame = grid::Frame::new(0, 0, 9, 1).next_frame();
+    let mut processes = Vec::new();
+    for _ in 0..2 {
+        let piece = frame.split(&grid::SplitStrategy::new().max_x(3, grid::Alignment::Minus)).ok_or(())?;
+        processes.push(piece.into_process(grid::DividerStrategy::Beginning));
+    }
+    processes.push(frame.into_process(grid::DividerStrategy::Beginning));
+    let count_moves = |actions: &[RecordedAction]| actions.iter().filter(|action| matches!(action, RecordedAction::MoveTo(..))).count();
+    let mut tracked = Vec::new();
+    let mut optimized = Vec::new();
+    let mut cursor = (0, 0);
+    for process in &mut processes {
+        process.add_to_section("ab".to_string(), &mut Truncate, grid::Alignment::Plus).map_err(|_| ())?;
+        process.print_tracking(&mut RecordingHandler, &mut tracked)?;
+        cursor = process.print_optimized(&mut RecordingHandler, &mut optimized, cursor)?;
+    }
+    assert_eq!(count_moves(&tracked), 3);
+    assert_eq!(count_moves(&optimized), 0);
+    assert_eq!(optimized.len(), 3);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn print_optimized<H: Handler>(
+        &self,
+        handler: &mut H,
+        out: &mut H::OutputDevice,
+        cursor: (usize, usize),
+    ) -> Result<(usize, usize), H::Error> {
+        let mut position = cursor;
+        self.grab_actions(|action| {
+            if matches!(action, Action::MoveTo(x, y) if (*x, *y) == position) {
+                return Ok(());
+            }
+            advance(&mut position, action);
+            handler.handle(out, action)
+        })?;
+        Ok(position)
     }
     /**
     Prints safely - this method cannot return an error.
@@ -575,10 +3584,283 @@ impl DrawProcess {
     # }
     ```
     */
-    pub fn print_safe<H: SafeHandler>(&mut self, handler: &mut H, out: &mut H::OutputDevice) {
-        let actions = self.grab_actions();
-        for line in actions {
-            handler.safe_handle(out, &line);
+    pub fn print_safe<H: SafeHandler>(&self, handler: &mut H, out: &mut H::OutputDevice) {
+        let _: Result<(), Infallible> = self.grab_actions(|action| {
+            handler.safe_handle(out, action);
+            Ok(())
+        });
+    }
+    /**
+    Prints out the grid using a handler, with the characters of each line in reverse order.
+    This naively reverses the characters, so combining characters will end up attached to the wrong character,
+    and the blank space added by trim strategies will end up on the left.
+    # Errors
+    Returns an error if the handler returns an error.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 6, 2).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    process.add_to_section("Some".to_string(), &mut Truncate, grid::Alignment::Plus);
+    let mut output: String = String::new();
+    process.print_mirrored(&mut out::OutToString, &mut output)?;
+    assert_eq!("  emoS\n      \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn print_mirrored<H: Handler>(&self, handler: &mut H, out: &mut H::OutputDevice) -> Result<(), H::Error> {
+        self.grab_actions(|action| match action {
+            Action::Print(text) => handler.handle(out, &Action::Print(&text.chars().rev().collect::<String>())),
+            Action::Hyperlink { uri, text } => handler.handle(out, &Action::Hyperlink { uri, text: &text.chars().rev().collect::<String>() }),
+            _ => handler.handle(out, action),
+        })
+    }
+    /**
+    Prints out the grid using a handler, like print(), but only showing the lines of content that the predicate accepts.
+    The content itself isn't changed, so a different filter can be used the next time it's printed.
+    If compact is false, rejected lines are printed as blank rows, and everything stays in place.
+    If compact is true, rejected lines are left out entirely: the rows after them move up, and the freed rows are printed blank at the bottom.
+    # Errors
+    Returns an error if the handler returns an error.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 3).next_frame();
+    let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    for line in ["WARN", "info", "WARN"].iter() {
+        process.add_to_section(line.to_string(), &mut Truncate, grid::Alignment::Plus);
+    }
+    let mut output: String = String::new();
+    process.print_filtered(&mut out::OutToString, &mut output, |line| line.0.starts_with("WARN"), false)?;
+    assert_eq!("WARN \n     \nWARN \n".to_string(), output);
+    let mut output: String = String::new();
+    process.print_filtered(&mut out::OutToString, &mut output, |line| line.0.starts_with("WARN"), true)?;
+    assert_eq!("WARN \nWARN \n     \n".to_string(), output);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn print_filtered<H: Handler, F: Fn(&TrimmedText) -> bool>(
+        &self,
+        handler: &mut H,
+        out: &mut H::OutputDevice,
+        predicate: F,
+        compact: bool,
+    ) -> Result<(), H::Error> {
+        let accepted = |row: &Row| match row {
+            Row::Content(_, _, line) => predicate(line),
+            _ => true,
+        };
+        let mut rows = self.rows();
+        if compact {
+            rows.retain(accepted);
+            rows.resize(self.height(), Row::Blank);
+        } else {
+            for row in rows.iter_mut().filter(|row| !accepted(row)) {
+                *row = Row::Blank;
+            }
+        }
+        self.grab_row_actions(&rows, |action| handler.handle(out, action))
+    }
+}
+
+/**
+Moves the boundary between two vertically adjacent processes by delta lines.
+A positive delta moves the boundary down, growing the upper process and shrinking the lower one. A negative delta does the opposite.
+The process that grows gains blank space next to the boundary. The process that shrinks loses its blank space first;
+if that isn't enough, its divider moves towards the outer edge to make room.
+# Errors
+Returns an error, leaving both processes untouched, if the upper process isn't directly above the lower one with the same width,
+if the boundary would move past the other edge of a process, or if the content of the shrinking process wouldn't fit anymore.
+Content is never dropped - remove some content from the shrinking process first if you need to move the boundary further.
+Bands (see DrawProcess::set_bands()) move along with the edge of the lower process, and each band's content has to keep fitting in it.
+Text written with DrawProcess::write_at() stays on the same screen row, unless the row isn't part of its process anymore.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::out;
+# use grid_ui::process::resize_split;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let mut upper = grid::Frame::new(0, 0, 5, 4).next_frame();
+let lower = upper.split(&grid::SplitStrategy::new().max_y(2, grid::Alignment::Plus)).ok_or(())?;
+let mut upper = upper.into_process(grid::DividerStrategy::Beginning);
+let mut lower = lower.into_process(grid::DividerStrategy::Beginning);
+upper.add_to_section("Up".to_string(), &mut Truncate, grid::Alignment::Plus);
+lower.add_to_section("Down".to_string(), &mut Truncate, grid::Alignment::Plus);
+assert!(resize_split(&mut upper, &mut lower, 1).is_ok());
+assert_eq!((upper.height(), lower.height()), (3, 1));
+let mut output: String = String::new();
+upper.print(&mut out::OutToString, &mut output)?;
+lower.print(&mut out::OutToString, &mut output)?;
+assert_eq!("Up   \n     \n     \nDown \n".to_string(), output);
+assert_eq!(resize_split(&mut upper, &mut lower, 1), Err(grid::GeometryError::Overflow(1)));
+assert_eq!(resize_split(&mut lower, &mut upper, 1), Err(grid::GeometryError::NotAdjacent));
+lower.write_at(3, 4, "W");
+assert!(resize_split(&mut upper, &mut lower, -2).is_ok());
+assert_eq!((upper.height(), lower.height()), (1, 3));
+assert_eq!(lower.row_at(3), Some("DownW".to_string()));
+# Ok(())
+# }
+```
+Moving the boundary with bands
+``` rust
+# use grid_ui::grid;
+# use grid_ui::process::resize_split;
+# use grid_ui::trim::Truncate;
+# fn main() -> Result<(), ()>{
+let mut upper = grid::Frame::new(0, 0, 5, 6).next_frame();
+let lower = upper.split(&grid::SplitStrategy::new().max_y(4, grid::Alignment::Plus)).ok_or(())?;
+let mut upper = upper.into_process(grid::DividerStrategy::Beginning);
+let mut lower = lower.into_process(grid::DividerStrategy::Beginning);
+lower.set_bands(&[0, 3]).map_err(|_| ())?;
+lower.add_to_band(2, "Foot".to_string(), &mut Truncate).map_err(|_| ())?;
+assert!(resize_split(&mut upper, &mut lower, 1).is_ok());
+let lines: Vec<String> = lower.rendered_lines().collect();
+assert_eq!(lines, vec!["     ", "     ", "Foot "]);
+lower.add_to_band(1, "Body".to_string(), &mut Truncate).map_err(|_| ())?;
+lower.add_to_band(1, "Body".to_string(), &mut Truncate).map_err(|_| ())?;
+assert_eq!(resize_split(&mut upper, &mut lower, 1), Err(grid::GeometryError::Overflow(1)));
+# Ok(())
+# }
+```
+*/
+pub fn resize_split(upper: &mut DrawProcess, lower: &mut DrawProcess, delta: isize) -> Result<(), GeometryError> {
+    if upper.start_x != lower.start_x || upper.end_x != lower.end_x || upper.end_y != lower.start_y {
+        return Err(GeometryError::NotAdjacent);
+    }
+    let amount = delta.unsigned_abs();
+    let shrinking: &DrawProcess = if delta < 0 { upper } else { lower };
+    let new_height = shrinking.height().checked_sub(amount).ok_or(GeometryError::OutOfBounds)?;
+    // The upper process loses rows at the bottom, so its bands stay put. The lower one loses them at the top, so its bands move up.
+    let starts = shrinking
+        .bands
+        .iter()
+        .map(|band| if delta < 0 { band.start.min(new_height) } else { band.start.saturating_sub(amount) })
+        .collect::<Vec<_>>();
+    let overflow = shrinking.overflow_with_bands(&starts, new_height);
+    if overflow > 0 {
+        return Err(GeometryError::Overflow(overflow));
+    }
+    // Writes are relative to the top as well, so the lower process's writes move to stay where they were.
+    if delta < 0 {
+        upper.end_y -= amount;
+        upper.writes.retain(|(y, _, _)| *y < new_height);
+        lower.start_y -= amount;
+        lower.divider += amount;
+        for band in lower.bands.iter_mut() {
+            band.start += amount;
+        }
+        for write in lower.writes.iter_mut() {
+            write.0 += amount;
+        }
+    } else {
+        upper.end_y += amount;
+        lower.start_y += amount;
+        lower.divider = lower.divider.saturating_sub(amount);
+        for (band, start) in lower.bands.iter_mut().zip(starts) {
+            band.start = start;
+        }
+        lower.writes.retain(|(y, _, _)| *y >= amount);
+        for write in lower.writes.iter_mut() {
+            write.0 -= amount;
+        }
+    }
+    upper.clamp_divider();
+    lower.clamp_divider();
+    Ok(())
+}
+/**
+Splits content into pages, each of which is a process the size of the page grid. The caller can then print one page at a time.
+All of the content is trimmed at once (use trim::WordWrap to word-wrap it), and then fills the plus section of each page in order,
+so each page holds as many lines as the plus section has room for, given the divider strategy. The last page holds whatever is left.
+Returns no pages if the content is empty, or if the plus section of a page has no room for anything.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::process::paginate;
+# use grid_ui::trim::WordWrap;
+# fn main() -> Result<(), ()>{
+let page = grid::Frame::new(0, 0, 6, 2).next_frame();
+let pages = paginate("one two three four five".to_string(), &page, &mut WordWrap, grid::DividerStrategy::Beginning);
+assert_eq!(pages.len(), 3);
+assert_eq!(pages[1].to_plain_lines(), vec!["three".to_string(), "four".to_string()]);
+assert_eq!(pages[2].to_plain_lines(), vec!["five".to_string()]);
+assert!(paginate(String::new(), &page, &mut WordWrap, grid::DividerStrategy::Beginning).is_empty());
+# Ok(())
+# }
+```
+*/
+pub fn paginate<T: TrimStrategy>(text: T::Input, page: &Grid, strategy: &mut T, divider: DividerStrategy) -> Vec<DrawProcess> {
+    if text.to_string().is_empty() {
+        return Vec::new();
+    }
+    let blank = DrawProcess::new(page.clone(), divider);
+    let capacity = blank.free_lines(Alignment::Plus);
+    if capacity == 0 {
+        return Vec::new();
+    }
+    let lines = strategy.trim(text, &blank, Alignment::Plus);
+    lines
+        .chunks(capacity)
+        .map(|chunk| {
+            let mut process = blank.clone();
+            for line in chunk {
+                process.add_to_section_trimmed(line.clone(), Alignment::Plus).expect("Each page has room for its lines");
+            }
+            process
+        })
+        .collect()
+}
+#[doc(hidden)]
+/// Gets the lines of a section that are inside its scroll window, along with the index of the first one.
+/// The window is moved back if it would go past the last line, so that it's full whenever there are enough lines.
+fn scroll_window(lines: &[TrimmedText], offset: usize, capacity: usize) -> (usize, &[TrimmedText]) {
+    let start = offset.min(lines.len().saturating_sub(capacity));
+    let end = (start + capacity).min(lines.len());
+    (start, &lines[start..end])
+}
+#[doc(hidden)]
+/// Moves a cursor position past an action, the way a terminal would.
+fn advance(position: &mut (usize, usize), action: &Action) {
+    match action {
+        Action::MoveTo(x, y) => *position = (*x, *y),
+        Action::MoveBy(dx, dy) => *position = (position.0.saturating_add_signed(*dx), position.1.saturating_add_signed(*dy)),
+        Action::Print(text) | Action::Hyperlink { text, .. } => position.0 += text_width(text),
+        Action::Repeat(c, n) => position.0 += n * text_width(c.encode_utf8(&mut [0; 4])),
+        Action::BeginSyncUpdate
+        | Action::EndSyncUpdate
+        | Action::SetColor(_)
+        | Action::SetBackground(_)
+        | Action::ResetColor
+        | Action::Clear
+        | Action::ClearLine => {}
+    }
+}
+#[doc(hidden)]
+/// Counts how many rows of content come before each row of a layout.
+fn content_rows<'a>(rows: &'a [Row]) -> impl Iterator<Item = usize> + 'a {
+    rows.iter().scan(0, |count, row| {
+        let before = *count;
+        if matches!(row, Row::Content(..)) {
+            *count += 1;
         }
+        Some(before)
+    })
+}
+#[doc(hidden)]
+/// Divides used by total, treating an empty total as nothing being used.
+fn ratio(used: usize, total: usize) -> f32 {
+    if total == 0 {
+        0.0
+    } else {
+        used as f32 / total as f32
     }
 }
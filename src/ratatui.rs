@@ -0,0 +1,113 @@
+use std::convert::TryFrom;
+
+use ::ratatui::{
+    buffer::Buffer,
+    style::{self, Style},
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    out::{Action, Color, SafeHandler},
+    trim::text_width,
+};
+/**
+A handler that draws into a ratatui buffer, so that processes can be drawn as part of a larger ratatui app.
+Text is put into the cells starting at the location of the last move, with the colors that are set at the time.
+Locations are the same as the ones in the buffer's area, so a process should be placed inside it.
+Anything that lands outside of the area is cut off instead of panicking.
+Clear resets the whole buffer, and ClearLine resets the row the cursor is on.
+# Example
+``` rust
+# use grid_ui::grid;
+# use grid_ui::ratatui::BufferHandler;
+# use grid_ui::trim::Ignore;
+# use ratatui::{buffer::Buffer, layout::Rect};
+# fn main() -> Result<(), ()>{
+let mut process = grid::Frame::new(1, 1, 6, 2).next_frame().into_process(grid::DividerStrategy::Beginning);
+process.add_to_section("Hello".to_string(), &mut Ignore, grid::Alignment::Plus);
+let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+process.print(&mut BufferHandler::new(), &mut buffer)?;
+assert_eq!(buffer, Buffer::with_lines(["    ", " Hel"]));
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BufferHandler {
+    x: usize,
+    y: usize,
+    style: Style,
+}
+impl BufferHandler {
+    /// Creates a handler, with the cursor at the top left corner and no colors set.
+    pub fn new() -> BufferHandler {
+        BufferHandler::default()
+    }
+    #[doc(hidden)]
+    /// Puts text into the buffer at the cursor, moving past it. Cells outside of the buffer are skipped.
+    fn write(&mut self, out: &mut Buffer, text: &str) {
+        for grapheme in text.graphemes(true) {
+            if let (Ok(x), Ok(y)) = (u16::try_from(self.x), u16::try_from(self.y)) {
+                if let Some(cell) = out.cell_mut((x, y)) {
+                    cell.set_symbol(grapheme).set_style(self.style);
+                }
+            }
+            self.x += text_width(grapheme);
+        }
+    }
+}
+impl SafeHandler for BufferHandler {
+    type OutputDevice = Buffer;
+    fn safe_handle(&mut self, out: &mut Buffer, input: &Action) {
+        match input {
+            Action::Print(s) | Action::Hyperlink { text: s, .. } => self.write(out, s),
+            Action::Repeat(c, n) => self.write(out, &std::iter::repeat(*c).take(*n).collect::<String>()),
+            Action::MoveTo(x, y) => {
+                self.x = *x;
+                self.y = *y;
+            }
+            Action::MoveBy(dx, dy) => {
+                self.x = self.x.saturating_add_signed(*dx);
+                self.y = self.y.saturating_add_signed(*dy);
+            }
+            Action::SetColor(color) => self.style = self.style.fg(ratatui_color(*color)),
+            Action::SetBackground(color) => self.style = self.style.bg(ratatui_color(*color)),
+            Action::ResetColor => self.style = Style::default(),
+            Action::Clear => out.reset(),
+            Action::ClearLine => {
+                let area = out.area;
+                if let Ok(y) = u16::try_from(self.y) {
+                    for x in area.left()..area.right() {
+                        if let Some(cell) = out.cell_mut((x, y)) {
+                            cell.reset();
+                        }
+                    }
+                }
+            }
+            Action::BeginSyncUpdate | Action::EndSyncUpdate => {}
+        }
+    }
+}
+#[doc(hidden)]
+/// Converts a color into the matching ratatui color.
+fn ratatui_color(color: Color) -> style::Color {
+    match color {
+        Color::Black => style::Color::Black,
+        Color::Red => style::Color::Red,
+        Color::Green => style::Color::Green,
+        Color::Yellow => style::Color::Yellow,
+        Color::Blue => style::Color::Blue,
+        Color::Magenta => style::Color::Magenta,
+        Color::Cyan => style::Color::Cyan,
+        Color::White => style::Color::Gray,
+        Color::BrightBlack => style::Color::DarkGray,
+        Color::BrightRed => style::Color::LightRed,
+        Color::BrightGreen => style::Color::LightGreen,
+        Color::BrightYellow => style::Color::LightYellow,
+        Color::BrightBlue => style::Color::LightBlue,
+        Color::BrightMagenta => style::Color::LightMagenta,
+        Color::BrightCyan => style::Color::LightCyan,
+        Color::BrightWhite => style::Color::White,
+        Color::Rgb(r, g, b) => style::Color::Rgb(r, g, b),
+    }
+}
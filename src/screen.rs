@@ -0,0 +1,179 @@
+use crate::{
+    grid::Alignment,
+    out::Handler,
+    process::DrawProcess,
+    trim::{FormatError, TrimStrategy},
+};
+
+/// A screen is a collection of processes (called panels), each with its own id, that are printed together.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::out;
+/// # use grid_ui::screen::Screen;
+/// # use grid_ui::trim::Truncate;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 5, 2).next_frame();
+/// let header = grid.split(&grid::SplitStrategy::new().max_y(1, grid::Alignment::Minus)).ok_or(())?;
+/// let mut screen = Screen::new();
+/// screen.add_panel("header", header.into_process(grid::DividerStrategy::Beginning));
+/// screen.add_panel("body", grid.into_process(grid::DividerStrategy::Beginning));
+/// screen.panel_mut("header").ok_or(())?.add_to_section("Title".to_string(), &mut Truncate, grid::Alignment::Plus);
+/// screen.panel_mut("body").ok_or(())?.add_to_section("Text".to_string(), &mut Truncate, grid::Alignment::Plus);
+/// let mut output: String = String::new();
+/// screen.print(&mut out::OutToString, &mut output)?;
+/// assert_eq!("Title\nText \n".to_string(), output);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Screen {
+    panels: Vec<(String, DrawProcess)>,
+}
+impl Screen {
+    /// Creates an empty screen.
+    pub fn new() -> Screen {
+        Screen { panels: Vec::new() }
+    }
+    /// Adds a panel to the screen. Panels are printed in the order they're added.
+    /// If a panel with the same id already exists, it's replaced.
+    pub fn add_panel(&mut self, id: &str, process: DrawProcess) {
+        if let Some(panel) = self.panel_mut(id) {
+            *panel = process;
+        } else {
+            self.panels.push((id.to_string(), process));
+        }
+    }
+    /// Removes a panel from the screen, giving it back if it exists.
+    pub fn remove_panel(&mut self, id: &str) -> Option<DrawProcess> {
+        let index = self.panels.iter().position(|(panel_id, _)| panel_id == id)?;
+        Some(self.panels.remove(index).1)
+    }
+    /// Gets a panel.
+    pub fn panel(&self, id: &str) -> Option<&DrawProcess> {
+        self.panels.iter().find(|(panel_id, _)| panel_id == id).map(|(_, process)| process)
+    }
+    /// Gets a panel mutably, so that content can be added to it.
+    pub fn panel_mut(&mut self, id: &str) -> Option<&mut DrawProcess> {
+        self.panels.iter_mut().find(|(panel_id, _)| panel_id == id).map(|(_, process)| process)
+    }
+    /// Gets the ids of all of the panels, in the order they're printed.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.panels.iter().map(|(id, _)| id.as_str())
+    }
+    /**
+    Adds content to a panel, recording any error in the sink instead of returning it.
+    This lets you fill an entire screen, and then check what didn't fit all at once.
+    Content for a panel that doesn't exist is ignored.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::screen::{ErrorSink, Screen};
+    # use grid_ui::trim::{FormatError, Truncate};
+    # fn main() -> Result<(), ()>{
+    let mut screen = Screen::new();
+    screen.add_panel("small", grid::Frame::new(0, 0, 5, 1).next_frame().into_process(grid::DividerStrategy::Beginning));
+    screen.add_panel("big", grid::Frame::new(0, 1, 5, 5).next_frame().into_process(grid::DividerStrategy::Beginning));
+    let mut sink = ErrorSink::new();
+    for id in ["small", "big"].iter() {
+        screen.add_to_panel(id, "One".to_string(), &mut Truncate, grid::Alignment::Plus, &mut sink);
+        screen.add_to_panel(id, "Two".to_string(), &mut Truncate, grid::Alignment::Plus, &mut sink);
+    }
+    let errors = sink.into_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, "small".to_string());
+    assert_eq!(errors[0].1.recovered(), Some(&"Two  ".to_string()));
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn add_to_panel<T: TrimStrategy>(&mut self, id: &str, text: T::Input, strategy: &mut T, section: Alignment, sink: &mut ErrorSink<T>) {
+        if let Some(panel) = self.panel_mut(id) {
+            if let Err(e) = panel.add_to_section(text, strategy, section) {
+                sink.record(id, e);
+            }
+        }
+    }
+    /**
+    Saves the screen as JSON, including every panel's position, size, divider, settings, and content.
+    The line cache isn't saved, so it's off in panels that are loaded again (see DrawProcess::set_line_cache()).
+    This is only available with the json feature.
+    # Example
+    ``` rust
+    # use grid_ui::grid;
+    # use grid_ui::out;
+    # use grid_ui::screen::Screen;
+    # use grid_ui::trim::Truncate;
+    # fn main() -> Result<(), ()>{
+    let mut grid = grid::Frame::new(0, 0, 5, 2).next_frame();
+    let header = grid.split(&grid::SplitStrategy::new().max_y(1, grid::Alignment::Minus)).ok_or(())?;
+    let mut screen = Screen::new();
+    screen.add_panel("header", header.into_process_aligned(grid::DividerStrategy::Beginning, grid::HAlign::Right));
+    screen.add_panel("body", grid.into_process(grid::DividerStrategy::End));
+    screen.panel_mut("header").ok_or(())?.add_to_section("Hi".to_string(), &mut Truncate, grid::Alignment::Plus);
+    screen.panel_mut("body").ok_or(())?.add_to_section("Text".to_string(), &mut Truncate, grid::Alignment::Minus);
+    let mut loaded = Screen::load(&screen.save()).map_err(|_| ())?;
+    assert_eq!(loaded, screen);
+    let (mut before, mut after) = (String::new(), String::new());
+    screen.print(&mut out::OutToString, &mut before)?;
+    loaded.print(&mut out::OutToString, &mut after)?;
+    assert_eq!(before, after);
+    # Ok(())
+    # }
+    ```
+    */
+    #[cfg(feature = "json")]
+    pub fn save(&self) -> String {
+        serde_json::to_string(self).expect("Screens can always be represented as JSON")
+    }
+    /// Loads a screen saved with save(). This is only available with the json feature.
+    /// # Errors
+    /// Returns an error if the JSON isn't a saved screen.
+    #[cfg(feature = "json")]
+    pub fn load(s: &str) -> Result<Screen, serde_json::Error> {
+        serde_json::from_str(s)
+    }
+    /// Prints every panel using a handler, in the order they were added.
+    /// # Errors
+    /// Returns an error as soon as the handler returns an error.
+    pub fn print<H: Handler>(&self, handler: &mut H, out: &mut H::OutputDevice) -> Result<(), H::Error> {
+        for (_, panel) in &self.panels {
+            panel.print(handler, out)?;
+        }
+        Ok(())
+    }
+}
+/// Collects formatting errors from several panels, so that they can be looked at together.
+/// For an example, see Screen::add_to_panel().
+#[derive(Debug)]
+pub struct ErrorSink<T: TrimStrategy> {
+    errors: Vec<(String, FormatError<T>)>,
+}
+impl<T: TrimStrategy> ErrorSink<T> {
+    /// Creates an empty sink.
+    pub fn new() -> ErrorSink<T> {
+        ErrorSink { errors: Vec::new() }
+    }
+    /// Records an error for a panel.
+    pub fn record(&mut self, id: &str, error: FormatError<T>) {
+        self.errors.push((id.to_string(), error));
+    }
+    /// Gets every error recorded so far, along with the id of the panel it came from.
+    pub fn errors(&self) -> &[(String, FormatError<T>)] {
+        &self.errors
+    }
+    /// Whether no errors have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+    /// Takes every error recorded, along with the id of the panel it came from.
+    pub fn into_errors(self) -> Vec<(String, FormatError<T>)> {
+        self.errors
+    }
+}
+impl<T: TrimStrategy> Default for ErrorSink<T> {
+    fn default() -> Self {
+        ErrorSink::new()
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{
+    grid::{DividerStrategy, Frame},
+    out::OutToString,
+    process::DrawProcess,
+};
+
+/// Builds a process of the given size, with its top left corner at (0, 0).
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::testing::build;
+/// let process = build(10, 3, grid::DividerStrategy::Beginning);
+/// assert_eq!((process.width(), process.height()), (10, 3));
+/// ```
+pub fn build(width: usize, height: usize, divider: DividerStrategy) -> DrawProcess {
+    Frame::new(0, 0, width, height).next_frame().into_process(divider)
+}
+/// Renders a process into a string using out::OutToString, so each row ends with a newline.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::testing::{build, render};
+/// # use grid_ui::trim::Truncate;
+/// let mut process = build(5, 2, grid::DividerStrategy::Beginning);
+/// process.add_to_section("Hi".to_string(), &mut Truncate, grid::Alignment::Plus);
+/// assert_eq!(render(&process), "Hi   \n     \n".to_string());
+/// ```
+pub fn render(process: &DrawProcess) -> String {
+    let mut output = String::new();
+    process.print_safe(&mut OutToString, &mut output);
+    output
+}
+/// Asserts that a process renders exactly the expected rows. Each row is given without its newline.
+/// # Panics
+/// Panics if the rows don't match, showing both the rendered rows and the expected ones.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::testing::{assert_renders, build};
+/// # use grid_ui::trim::Truncate;
+/// let mut process = build(5, 2, grid::DividerStrategy::End);
+/// process.add_to_section("Hi".to_string(), &mut Truncate, grid::Alignment::Minus);
+/// assert_renders(&process, &["     ", "Hi   "]);
+/// ```
+pub fn assert_renders(process: &DrawProcess, expected: &[&str]) {
+    let rendered = render(process);
+    let rows = rendered.lines().collect::<Vec<_>>();
+    assert_eq!(rows, expected, "The process didn't render the expected rows.\nRendered:\n{}", rendered);
+}
@@ -1,6 +1,7 @@
 use std::{
     error::Error,
     fmt::{Debug, Display},
+    hash::{Hash, Hasher},
 };
 
 use unicode_segmentation::UnicodeSegmentation;
@@ -9,7 +10,8 @@ use crate::{grid::Alignment, process::DrawProcess};
 
 /// Represents a formatting problem. Contains the original inputted string, restored as close to its original glory as possible. 
 /// Note that some of the information in the string may be lost.
-/// Currently, there's only one variant of this error, indicating a lack of space.
+/// NoSpace means that there wasn't enough space for the text, and NoLine means that a line that doesn't exist was going to be replaced.
+/// More variants may be added later, so matching on it needs a wildcard arm.
 /// # Examples  
 /// ``` rust
 /// # use grid_ui::grid;
@@ -30,24 +32,135 @@ use crate::{grid::Alignment, process::DrawProcess};
 /// ```
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum FormatError<T: TrimStrategy> {
     NoSpace(T::Input),
+    NoLine(T::Input),
+}
+impl<T: TrimStrategy> FormatError<T> {
+    /// Gets the content that couldn't be added, if the error has any.
+    /// ``` rust
+    /// # use grid_ui::grid;
+    /// # use grid_ui::trim::Ignore;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>>{
+    /// let mut grid = grid::Frame::new(0, 0, 10, 1).next_frame();
+    /// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+    /// process.add_to_section("Some stuff".to_string(), &mut Ignore, grid::Alignment::Plus)?;
+    /// let e = process.add_to_section("No more".to_string(), &mut Ignore, grid::Alignment::Plus).unwrap_err();
+    /// assert_eq!(e.recovered(), Some(&"No more".to_string()));
+    /// assert_eq!(e.to_string(), "No space found for No more".to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn recovered(&self) -> Option<&T::Input> {
+        match self {
+            FormatError::NoSpace(value) | FormatError::NoLine(value) => Some(value),
+        }
+    }
 }
 impl<T: TrimStrategy> Display for FormatError<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             FormatError::NoSpace(value) => write!(f, "No space found for {}", value),
+            FormatError::NoLine(value) => write!(f, "No line found to replace with {}", value),
         }
     }
 }
 impl<T: TrimStrategy> Error for FormatError<T> {}
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
+#[derive(Debug, Clone, Default)]
 /// Trimmed text is text that is marked as processed and displayable.
 /// It is only public so that users can create TrimStrategy objects other than the 3 provided.
-/// It is not meant to be manually be created by anything other than a TrimStrategy.
-pub struct TrimmedText(pub String);
+/// It is not meant to be manually be created by anything other than a TrimStrategy, which can make it with new(), padded(), or from().
+/// Trimmed text also remembers where its content ends, before the blank space that short lines are padded with (see unpadded()).
+/// That's only used to place the content, so trimmed text is compared and hashed by its text alone.
+pub struct TrimmedText(pub String, pub(crate) usize);
+impl PartialEq for TrimmedText {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl Eq for TrimmedText {}
+impl Hash for TrimmedText {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+impl From<String> for TrimmedText {
+    /// Makes trimmed text out of a string that's all content, without any padding.
+    fn from(text: String) -> TrimmedText {
+        let len = text.len();
+        TrimmedText(text, len)
+    }
+}
+impl TrimmedText {
+    /// Makes trimmed text out of a string, checking that it fits in max_width columns first.
+    /// This is the safe way for a TrimStrategy outside of this crate to make its output.
+    /// The error is generic, so a strategy can return it as its own kind of error.
+    /// # Errors
+    /// Returns a NoSpace error with the string if the string is wider than max_width.
+    /// ``` rust
+    /// # use grid_ui::trim::{TrimmedText, Truncate};
+    /// # fn main() {
+    /// assert_eq!(TrimmedText::new::<Truncate>("small".to_string(), 5).ok(), Some(TrimmedText::from("small".to_string())));
+    /// let e = TrimmedText::new::<Truncate>("too long".to_string(), 5).unwrap_err();
+    /// assert_eq!(e.recovered(), Some(&"too long".to_string()));
+    /// # }
+    /// ```
+    pub fn new<T: TrimStrategy<Input = String>>(s: String, max_width: usize) -> Result<TrimmedText, FormatError<T>> {
+        if text_width(&s) > max_width {
+            return Err(FormatError::NoSpace(s));
+        }
+        Ok(TrimmedText::from(s))
+    }
+    /// Makes trimmed text out of text that's cut down to width columns, and then padded with blank space to take up exactly width columns.
+    /// The padding isn't content, so unpadded() leaves it out.
+    /// ``` rust
+    /// # use grid_ui::trim::TrimmedText;
+    /// # fn main() {
+    /// let text = TrimmedText::padded("small", 7);
+    /// assert_eq!((text.as_str(), text.unpadded()), ("small  ", "small"));
+    /// assert_eq!(TrimmedText::padded("too long", 5).as_str(), "too l");
+    /// # }
+    /// ```
+    pub fn padded(text: &str, width: usize) -> TrimmedText {
+        TrimmedText(fit(text, width), cut(text, width).len())
+    }
+    /// Gets the text, including any blank space added to it.
+    /// ``` rust
+    /// # use grid_ui::trim::TrimmedText;
+    /// # fn main() {
+    /// assert_eq!(TrimmedText::padded("small", 6).as_str(), "small ");
+    /// # }
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    /// Turns the trimmed text back into a string, including any blank space added to it.
+    /// ``` rust
+    /// # use grid_ui::trim::TrimmedText;
+    /// # fn main() {
+    /// assert_eq!(TrimmedText::padded("small", 6).into_string(), "small ".to_string());
+    /// # }
+    /// ```
+    pub fn into_string(self) -> String {
+        self.0
+    }
+    /// Gets the text without the blank space that trim strategies add to the end of short lines.
+    /// Spaces that were part of the content are kept, since the end of the content is remembered when the text is trimmed.
+    /// If the text has been changed since then, so that the end of the content isn't in it anymore, all of it is treated as content.
+    /// ``` rust
+    /// # use grid_ui::trim::TrimmedText;
+    /// # fn main() {
+    /// assert_eq!(TrimmedText::padded("small", 10).unpadded(), "small");
+    /// assert_eq!(TrimmedText::padded("spaced  ", 10).unpadded(), "spaced  ");
+    /// # }
+    /// ```
+    pub fn unpadded(&self) -> &str {
+        self.0.get(..self.1).unwrap_or(&self.0)
+    }
+}
 
 /// This trait is used for debug purposes.
 /// T implements DisplayAndDebug iff T implements Display and T implements Debug.
@@ -95,11 +208,11 @@ where
 /// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
 /// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
 /// let v = Ignore.trim("small".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("small".to_string())], v);
+/// assert_eq!(vec![TrimmedText::from("small".to_string())], v);
 /// let v = Ignore.trim("This fits.".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("This fits.".to_string())], v);
+/// assert_eq!(vec![TrimmedText::from("This fits.".to_string())], v);
 /// let v = Ignore.trim("This is a really long line that will break things in a terminal setup.".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("This is a really long line that will break things in a terminal setup.".to_string())], v);
+/// assert_eq!(vec![TrimmedText::from("This is a really long line that will break things in a terminal setup.".to_string())], v);
 /// # Ok(())
 /// # }
 /// ```
@@ -112,7 +225,7 @@ impl Display for Ignore {
 impl TrimStrategy for Ignore {
     type Input = String;
     fn trim(&mut self, text: String, _: &DrawProcess, _: Alignment) -> Vec<TrimmedText> {
-        vec![TrimmedText(text)]
+        vec![TrimmedText::from(text)]
     }
 
     fn back(&mut self, text: Vec<TrimmedText>, _: &DrawProcess, _: Alignment) -> Self::Input {
@@ -122,6 +235,7 @@ impl TrimStrategy for Ignore {
 #[derive(Debug)]
 /// The trim strategy cuts out anything that doesn't fit into the box in order to deal with grid restrictions.
 /// It also adds blank space to any short lines to make sure every bit of blank space is refreshed.
+/// With the unicode-width feature, wide characters (like CJK) take up two columns, and one that would stick out past the edge is replaced by blank space.
 /// # Example
 /// ``` rust
 /// # use grid_ui::grid;
@@ -133,11 +247,17 @@ impl TrimStrategy for Ignore {
 /// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
 /// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
 /// let v = Truncate.trim("small".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("small     ".to_string())], v);
+/// assert_eq!(vec![TrimmedText::from("small     ".to_string())], v);
 /// let v = Truncate.trim("This fits.".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("This fits.".to_string())], v);
+/// assert_eq!(vec![TrimmedText::from("This fits.".to_string())], v);
 /// let v = Truncate.trim("This is a really long line that will break things in a terminal setup.".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("This is a ".to_string())], v);
+/// assert_eq!(vec![TrimmedText::from("This is a ".to_string())], v);
+/// # #[cfg(feature = "unicode-width")] {
+/// let v = Truncate.trim("ab日本語日本".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("ab日本語日".to_string())], v);
+/// let v = Truncate.trim("a日本語日本".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("a日本語日 ".to_string())], v);
+/// # }
 /// # Ok(())
 /// # }
 /// ```
@@ -150,10 +270,61 @@ impl Display for Truncate {
 impl TrimStrategy for Truncate {
     type Input = String;
     fn trim(&mut self, text: String, chunk: &DrawProcess, _: Alignment) -> Vec<TrimmedText> {
-        let blank_space = " ".graphemes(true).cycle();
-        let res = text.graphemes(true).chain(blank_space).take(chunk.width()).collect();
-        vec![TrimmedText(res)]
+        vec![TrimmedText::padded(&text, chunk.width())]
+    }
+    fn back(&mut self, text: Vec<TrimmedText>, _: &DrawProcess, _: Alignment) -> Self::Input {
+        text.into_iter().next().expect("Safe unwrap").0
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// This strategy works like Truncate, but ends text that doesn't fit with an ellipsis, so that it's clear that something was cut off.
+/// The result is always a single line of exactly the process's width. The ellipsis is "…" by default; use "..." for terminals without unicode.
+/// If the process is narrower than the ellipsis, the ellipsis itself is cut off.
+/// The text that was cut off can't be given back.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::Ellipsis;
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let v = Ellipsis::default().trim("small".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("small     ".to_string())], v);
+/// let v = Ellipsis::default().trim("a very long label".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("a very lo…".to_string())], v);
+/// let v = Ellipsis { ellipsis: "...".to_string() }.trim("a very long label".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("a very ...".to_string())], v);
+/// let v = Ellipsis { ellipsis: "<cut off>...".to_string() }.trim("a very long label".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("<cut off>.".to_string())], v);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Ellipsis {
+    pub ellipsis: String,
+}
+impl Default for Ellipsis {
+    fn default() -> Self {
+        Ellipsis { ellipsis: "…".to_string() }
+    }
+}
+impl Display for Ellipsis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl TrimStrategy for Ellipsis {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, _: Alignment) -> Vec<TrimmedText> {
+        let width = chunk.width();
+        if text_width(&text) <= width {
+            return vec![TrimmedText::padded(&text, width)];
+        }
+        let kept = cut(&text, width.saturating_sub(text_width(&self.ellipsis)));
+        vec![TrimmedText::padded(&format!("{}{}", kept, self.ellipsis), width)]
     }
+    /// The text that was cut off is lost, so the line is given back with its ellipsis.
     fn back(&mut self, text: Vec<TrimmedText>, _: &DrawProcess, _: Alignment) -> Self::Input {
         text.into_iter().next().expect("Safe unwrap").0
     }
@@ -174,11 +345,11 @@ impl TrimStrategy for Truncate {
 /// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
 /// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
 /// let v = Split.trim("small".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("small     ".to_string())], v);
+/// assert_eq!(vec![TrimmedText::from("small     ".to_string())], v);
 /// let v = Split.trim("This fits.".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("This fits.".to_string())], v);
+/// assert_eq!(vec![TrimmedText::from("This fits.".to_string())], v);
 /// let v = Split.trim("This is a little too big..".to_string(), &process, grid::Alignment::Plus);
-/// assert_eq!(vec![TrimmedText("This is a ".to_string()), TrimmedText("little too".to_string()), TrimmedText(" big..    ".to_string())], v);
+/// assert_eq!(vec![TrimmedText::from("This is a ".to_string()), TrimmedText::from("little too".to_string()), TrimmedText::from(" big..    ".to_string())], v);
 /// # Ok(())
 /// # }
 /// ```
@@ -191,28 +362,24 @@ impl Display for Split {
 impl TrimStrategy for Split {
     type Input = String;
     fn trim(&mut self, text: String, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
-        let mut v = text.graphemes(true).collect::<Vec<_>>();
-        if v.is_empty() {
-            v.push(" ");
-        } // An empty string won't create a line break unless we do this.
-          // Stores the previous value
-        let mut storage: &[&str] = &[];
+        assert!(chunk.width() > 0, "Text can't be split into lines of 0 width");
         // The trimmed text result
         let mut res: Vec<TrimmedText> = Vec::new();
-        for line in v.chunks(chunk.width()) {
-            // each line, except for the last one, extends the entire grid. We only need to add extra blank space on the next one.
-            // As long as there's an item after, we don't need to extend the line with blank space.
-            if !storage.is_empty() {
-                res.push(TrimmedText(storage.iter().copied().collect::<String>()));
+        let mut rest = text.as_str();
+        // An empty string still makes one line, so this runs at least once.
+        loop {
+            let mut line = cut(rest, chunk.width());
+            if line.is_empty() {
+                // A character too wide for a line gets a line of its own anyway, so that the loop always finishes.
+                line = rest.graphemes(true).next().unwrap_or("");
             }
-            storage = line;
-        }
-        // Creates a cycle of blank space to extend the line with until the end of the chunk (to make sure no extra text from the chunk stays).
-        let blank_space = " ".graphemes(true).cycle();
-        // Adds a TrimmedText value of exactly the right visual length.
-        res.push(TrimmedText(
-            storage.iter().copied().chain(blank_space).take(chunk.width()).collect::<String>(),
-        ));
+            rest = &rest[line.len()..];
+            // Adds a TrimmedText value of exactly the right visual length, padding it with blank space if it's short.
+            res.push(TrimmedText::padded(line, chunk.width()));
+            if rest.is_empty() {
+                break;
+            }
+        }
         if matches!(a, Alignment::Minus) {
             // Reverses the direction if we're in the minus direction.
             res.reverse();
@@ -236,3 +403,548 @@ impl TrimStrategy for Split {
         res
     }
 }
+#[derive(Debug, Clone, PartialEq)]
+/// This strategy draws a horizontal gauge, showing where a value lies between a minimum and a maximum.
+/// The inputted text is used as a label in front of the gauge, and the gauge takes up the rest of the line.
+/// If show_value is set, the value and the maximum are shown after the gauge.
+/// Values outside of the range are clamped to it. If min and max are the same, the gauge is full when the value reaches max, and empty otherwise.
+/// If the label and value don't leave any room for the gauge, the line is truncated to fit.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::Gauge;
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 20, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let mut gauge = Gauge { min: 0.0, max: 100.0, value: 42.0, show_value: true };
+/// let v = gauge.trim("CPU ".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("CPU [███░░░░] 42/100".to_string())], v);
+/// gauge.value = 150.0;
+/// gauge.show_value = false;
+/// let v = gauge.trim("".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("[██████████████████]".to_string())], v);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Gauge {
+    pub min: f64,
+    pub max: f64,
+    pub value: f64,
+    pub show_value: bool,
+}
+impl Display for Gauge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl TrimStrategy for Gauge {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, _: Alignment) -> Vec<TrimmedText> {
+        let value = self.value.max(self.min).min(self.max);
+        let ratio = if self.max > self.min {
+            (value - self.min) / (self.max - self.min)
+        } else if self.value >= self.max {
+            1.0
+        } else {
+            0.0
+        };
+        let value_label = if self.show_value { format!(" {}/{}", value, self.max) } else { String::new() };
+        // The label, the value, and the two brackets all take up space.
+        let used = text_width(&text) + text_width(&value_label) + 2;
+        let bar_width = chunk.width().saturating_sub(used);
+        let filled = ((ratio * bar_width as f64).round() as usize).min(bar_width);
+        let line = format!("{}[{}{}]{}", text, "█".repeat(filled), "░".repeat(bar_width - filled), value_label);
+        vec![TrimmedText::padded(&line, chunk.width())]
+    }
+    /// The label can't always be told apart from the gauge, so the entire line is given back.
+    fn back(&mut self, text: Vec<TrimmedText>, _: &DrawProcess, _: Alignment) -> Self::Input {
+        text.into_iter().next().expect("Safe unwrap").0
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// This strategy packs many short items into columns, like ls does. Each string of the input is an item.
+/// Items flow from left to right, and then from top to bottom, with as many columns as the process's width allows.
+/// Each item is padded to cell_width, and there are gap spaces between columns. Items that are too long are cut off with an ellipsis.
+/// The last row can have fewer items than the others; it's padded with blank space, like every other row.
+/// Giving the text back splits the items apart again, but text cut off by the ellipsis (and blank items at the end) can't be recovered.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::{ColumnItems, ColumnList};
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 15, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let mut columns = ColumnList { cell_width: 4, gap: 1 };
+/// let v = columns.trim(["a", "bb", "longer", "d", "e"].iter().copied().collect(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("a    bb   lon… ".to_string()), TrimmedText::from("d    e         ".to_string())], v);
+/// assert_eq!(columns.back(v, &process, grid::Alignment::Plus), ColumnItems(vec!["a", "bb", "lon…", "d", "e"].iter().map(|item| item.to_string()).collect()));
+/// // Cells with no width still don't divide by zero.
+/// let v = ColumnList { cell_width: 0, gap: 0 }.trim(ColumnItems(vec!["a".to_string()]), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("               ".to_string())], v);
+/// # Ok(())
+/// # }
+/// ```
+pub struct ColumnList {
+    pub cell_width: usize,
+    pub gap: usize,
+}
+impl ColumnList {
+    #[doc(hidden)]
+    /// Finds the width of each cell, and the number of columns that fit in a process.
+    fn layout(&self, chunk: &DrawProcess) -> (usize, usize) {
+        let cell_width = self.cell_width.min(chunk.width());
+        // Cells that take up no space at all would fit forever, so each one counts as taking at least a column.
+        let columns = ((chunk.width() + self.gap) / (cell_width + self.gap).max(1)).max(1);
+        (cell_width, columns)
+    }
+}
+impl Display for ColumnList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+/// The items that a ColumnList packs into columns. It can be collected from any iterator of things that can be turned into strings.
+pub struct ColumnItems(pub Vec<String>);
+impl Display for ColumnItems {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.join("\n"))
+    }
+}
+impl<S: Into<String>> std::iter::FromIterator<S> for ColumnItems {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        ColumnItems(iter.into_iter().map(Into::into).collect())
+    }
+}
+impl TrimStrategy for ColumnList {
+    type Input = ColumnItems;
+    fn trim(&mut self, text: ColumnItems, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let (cell_width, columns) = self.layout(chunk);
+        let cells = text
+            .0
+            .iter()
+            .map(|item| {
+                if text_width(item) > cell_width && cell_width > 0 {
+                    // Cuts the item off, leaving room for the ellipsis.
+                    TrimmedText::padded(&format!("{}…", cut(item, cell_width - 1)), cell_width)
+                } else {
+                    TrimmedText::padded(item, cell_width)
+                }
+            })
+            .collect::<Vec<_>>();
+        let gap = " ".repeat(self.gap);
+        let mut res = cells
+            .chunks(columns)
+            .map(|row| {
+                // The last cell's padding is the same as the row's, so the row's content ends where the last item does.
+                let (last, row) = row.split_last().expect("Chunks are never empty");
+                let mut line = row.iter().map(|cell| format!("{}{}", cell.as_str(), gap)).collect::<String>();
+                line.push_str(last.unpadded());
+                TrimmedText::padded(&line, chunk.width())
+            })
+            .collect::<Vec<_>>();
+        if matches!(a, Alignment::Minus) {
+            // Reverses the direction if we're in the minus direction.
+            res.reverse();
+        }
+        res
+    }
+    fn back(&mut self, mut text: Vec<TrimmedText>, chunk: &DrawProcess, a: Alignment) -> Self::Input {
+        let (cell_width, columns) = self.layout(chunk);
+        if matches!(a, Alignment::Minus) {
+            text.reverse();
+        }
+        let mut items = Vec::new();
+        for line in text {
+            for column in 0..columns {
+                let cell = skip_columns(&line.0, column * (cell_width + self.gap)).0;
+                items.push(cut(cell, cell_width).trim_end_matches(' ').to_string());
+            }
+        }
+        // The last row is padded with blank cells, which weren't part of the input.
+        while items.len() > 1 && items.last().is_some_and(String::is_empty) {
+            items.pop();
+        }
+        ColumnItems(items)
+    }
+}
+#[derive(Debug)]
+/// This strategy wraps the text into lines, breaking them between words. Each line of the inputted text starts a new line.
+/// Words that are too long to fit on a line are split, and every line is padded with blank space, like Split does.
+/// Giving the text back joins the lines with single spaces, so the original line breaks and runs of spaces are lost.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::WordWrap;
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let v = WordWrap.trim("This is a little\nbit too big".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![
+///     TrimmedText::from("This is a ".to_string()),
+///     TrimmedText::from("little    ".to_string()),
+///     TrimmedText::from("bit too   ".to_string()),
+///     TrimmedText::from("big       ".to_string()),
+/// ], v);
+/// assert_eq!(WordWrap.back(v, &process, grid::Alignment::Plus), "This is a little bit too big".to_string());
+/// # Ok(())
+/// # }
+/// ```
+pub struct WordWrap;
+impl Display for WordWrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl TrimStrategy for WordWrap {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let mut res = text
+            .split('\n')
+            .flat_map(|line| wrap_words(line, chunk.width().max(1)))
+            .map(|line| TrimmedText::padded(&line, chunk.width()))
+            .collect::<Vec<_>>();
+        if matches!(a, Alignment::Minus) {
+            // Reverses the direction if we're in the minus direction.
+            res.reverse();
+        }
+        res
+    }
+    fn back(&mut self, mut text: Vec<TrimmedText>, _: &DrawProcess, a: Alignment) -> Self::Input {
+        if matches!(a, Alignment::Minus) {
+            text.reverse();
+        }
+        let lines = text.iter().map(|line| line.0.trim_matches(' ')).filter(|line| !line.is_empty()).collect::<Vec<_>>();
+        lines.join(" ")
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// This strategy formats the text as an item in a bulleted list. The text is word-wrapped,
+/// and the lines after the first are indented by the marker's width, so that they line up with the text instead of the marker.
+/// The marker is added to the start of the text, unless the text already starts with it. Words that are too long to fit on a line are split.
+/// Every line is padded with blank space, like Split does.
+/// Giving the text back joins the lines with single spaces, keeping the marker at the start.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::BulletList;
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 12, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let mut bullets = BulletList { marker: "• ".to_string() };
+/// let v = bullets.trim("Bullets wrap under the text".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![
+///     TrimmedText::from("• Bullets   ".to_string()),
+///     TrimmedText::from("  wrap under".to_string()),
+///     TrimmedText::from("  the text  ".to_string()),
+/// ], v);
+/// assert_eq!(bullets.back(v, &process, grid::Alignment::Plus), "• Bullets wrap under the text".to_string());
+/// let v = bullets.trim("• Marked".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("• Marked    ".to_string())], v);
+/// # Ok(())
+/// # }
+/// ```
+pub struct BulletList {
+    pub marker: String,
+}
+impl Display for BulletList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl TrimStrategy for BulletList {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let text = text.strip_prefix(self.marker.as_str()).unwrap_or(&text);
+        let indent_width = text_width(&self.marker);
+        let indent = " ".repeat(indent_width);
+        let lines = wrap_words(text, chunk.width().saturating_sub(indent_width).max(1));
+        let mut res = lines
+            .into_iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let start = if i == 0 { &self.marker } else { &indent };
+                TrimmedText::padded(&format!("{}{}", start, line), chunk.width())
+            })
+            .collect::<Vec<_>>();
+        if matches!(a, Alignment::Minus) {
+            // Reverses the direction if we're in the minus direction.
+            res.reverse();
+        }
+        res
+    }
+    fn back(&mut self, mut text: Vec<TrimmedText>, _: &DrawProcess, a: Alignment) -> Self::Input {
+        if matches!(a, Alignment::Minus) {
+            text.reverse();
+        }
+        let indent_width = text_width(&self.marker);
+        let words = text
+            .iter()
+            .map(|line| skip_columns(&line.0, indent_width).0.trim_matches(' ').to_string())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>();
+        format!("{}{}", self.marker, words.join(" "))
+    }
+}
+#[doc(hidden)]
+/// Splits text into lines of at most width columns, breaking it between words where possible.
+/// Always returns at least one line.
+fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut len = 0;
+    for mut word in text.split(' ').filter(|word| !word.is_empty()) {
+        if len > 0 && len + 1 + text_width(word) > width {
+            lines.push(std::mem::take(&mut line));
+            len = 0;
+        }
+        if len > 0 {
+            line.push(' ');
+            len += 1;
+        }
+        // Words that can't fit on a line of their own are split up.
+        while text_width(word) > width {
+            let mut part = cut(word, width);
+            if part.is_empty() {
+                // A character too wide for a line gets a line of its own anyway.
+                part = word.graphemes(true).next().unwrap_or("");
+            }
+            lines.push(part.to_string());
+            word = &word[part.len()..];
+        }
+        line.push_str(word);
+        len += text_width(word);
+    }
+    lines.push(line);
+    lines
+}
+#[doc(hidden)]
+/// Gets how many columns of the terminal text takes up. With the unicode-width feature, this is its display width,
+/// so wide characters (like CJK) take up two columns. Otherwise, every grapheme takes up one column.
+pub(crate) fn text_width(text: &str) -> usize {
+    #[cfg(feature = "unicode-width")]
+    return unicode_width::UnicodeWidthStr::width(text);
+    #[cfg(not(feature = "unicode-width"))]
+    return text.graphemes(true).count();
+}
+#[doc(hidden)]
+/// Gets the longest start of the text that fits in width columns, without splitting any graphemes.
+pub(crate) fn cut(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (i, grapheme) in text.grapheme_indices(true) {
+        used += text_width(grapheme);
+        if used > width {
+            return &text[..i];
+        }
+    }
+    text
+}
+#[doc(hidden)]
+/// Drops graphemes from the start of the text until at least width columns are gone. Gives back the rest of the text,
+/// and how many columns were dropped, which is more than width if a wide character was in the way.
+pub(crate) fn skip_columns(text: &str, width: usize) -> (&str, usize) {
+    let kept = cut(text, width);
+    let mut skipped = text_width(kept);
+    let mut rest = &text[kept.len()..];
+    if skipped < width {
+        if let Some(grapheme) = rest.graphemes(true).next() {
+            skipped += text_width(grapheme);
+            rest = &rest[grapheme.len()..];
+        }
+    }
+    (rest, skipped)
+}
+#[doc(hidden)]
+/// Cuts the text down to width columns, and then pads it with blank space so that it takes up exactly width columns.
+/// A wide character that would stick out past the edge is left out, and its column is padded instead.
+pub(crate) fn fit(text: &str, width: usize) -> String {
+    let text = cut(text, width);
+    let mut res = text.to_string();
+    res.extend(std::iter::repeat(' ').take(width.saturating_sub(text_width(text))));
+    res
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// This strategy puts a border on each side of every line, like "│ text │". The text is word-wrapped to fit between the borders,
+/// and each line is padded with blank space so that the right border lines up at the edge of the process.
+/// If the borders take up the entire width, a single line with just the borders (cut off at the edge) is made, and the text is dropped.
+/// Giving the text back removes the borders and joins the lines with single spaces.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::Bordered;
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 12, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let mut bordered = Bordered { left: "│ ".to_string(), right: " │".to_string() };
+/// let v = bordered.trim("Some text here".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("│ Some     │".to_string()), TrimmedText::from("│ text     │".to_string()), TrimmedText::from("│ here     │".to_string())], v);
+/// assert_eq!(bordered.back(v, &process, grid::Alignment::Plus), "Some text here".to_string());
+/// let v = Bordered { left: "[[[[[[".to_string(), right: "]]]]]]".to_string() }.trim("Hidden".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("[[[[[[]]]]]]".to_string())], v);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Bordered {
+    pub left: String,
+    pub right: String,
+}
+impl Display for Bordered {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl TrimStrategy for Bordered {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let borders = text_width(&self.left) + text_width(&self.right);
+        if borders >= chunk.width() {
+            return vec![TrimmedText::from(cut(&format!("{}{}", self.left, self.right), chunk.width()).to_string())];
+        }
+        let interior = chunk.width() - borders;
+        let mut res = wrap_words(&text, interior)
+            .into_iter()
+            .map(|line| TrimmedText::from(format!("{}{}{}", self.left, fit(&line, interior), self.right)))
+            .collect::<Vec<_>>();
+        if matches!(a, Alignment::Minus) {
+            // Reverses the direction if we're in the minus direction.
+            res.reverse();
+        }
+        res
+    }
+    fn back(&mut self, mut text: Vec<TrimmedText>, chunk: &DrawProcess, a: Alignment) -> Self::Input {
+        let left = text_width(&self.left);
+        let right = text_width(&self.right);
+        if left + right >= chunk.width() {
+            return String::new();
+        }
+        if matches!(a, Alignment::Minus) {
+            text.reverse();
+        }
+        let interior = chunk.width() - left - right;
+        let lines = text
+            .iter()
+            .map(|line| cut(skip_columns(&line.0, left).0, interior).trim_matches(' ').to_string())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>();
+        lines.join(" ")
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// This strategy expands every tab in the text into spaces, up to the next tab stop, and then hands the text to another strategy.
+/// Tab stops are placed every tab_width columns, counting from the start of the text. A tab_width of 0 removes tabs entirely.
+/// A tab that would run past the edge of the process is trimmed by the inner strategy, like any other text.
+/// Giving the text back is left to the inner strategy, so the tabs don't come back as tabs.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::TabExpand;
+/// # use grid_ui::trim::Truncate;
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let mut tabs = TabExpand { tab_width: 4, inner: Truncate };
+/// let v = tabs.trim("a\tb".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("a   b     ".to_string())], v);
+/// let v = tabs.trim("abcdefghi\tj".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("abcdefghi ".to_string())], v);
+/// # Ok(())
+/// # }
+/// ```
+pub struct TabExpand<T: TrimStrategy<Input = String>> {
+    pub tab_width: usize,
+    pub inner: T,
+}
+impl<T: TrimStrategy<Input = String>> Display for TabExpand<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<T: TrimStrategy<Input = String>> TrimStrategy for TabExpand<T> {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let mut res = String::new();
+        let mut column = 0;
+        for grapheme in text.graphemes(true) {
+            if grapheme == "\t" {
+                if self.tab_width > 0 {
+                    let spaces = self.tab_width - column % self.tab_width;
+                    res.extend(std::iter::repeat(' ').take(spaces));
+                    column += spaces;
+                }
+            } else {
+                res.push_str(grapheme);
+                column += text_width(grapheme);
+            }
+        }
+        self.inner.trim(res, chunk, a)
+    }
+    fn back(&mut self, text: Vec<TrimmedText>, chunk: &DrawProcess, a: Alignment) -> Self::Input {
+        self.inner.back(text, chunk, a)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// This strategy splits the text at every newline, and trims each line with another strategy, so each line starts on its own row.
+/// A single newline at the end of the text doesn't make a blank row; two of them do. "\r\n" line endings are handled too.
+/// Text with no lines at all (an empty string) is still handed to the inner strategy once, so a blank row is made.
+/// Giving the text back gives each row back through the inner strategy, and joins the results with newlines.
+/// This means a line that the inner strategy spread across multiple rows comes back as multiple lines.
+/// # Example
+/// ``` rust
+/// # use grid_ui::grid;
+/// # use grid_ui::trim::Multiline;
+/// # use grid_ui::trim::Truncate;
+/// # use grid_ui::trim::TrimStrategy;
+/// # use grid_ui::trim::TrimmedText;
+/// # fn main() -> Result<(), ()>{
+/// let mut grid = grid::Frame::new(0, 0, 10, 3).next_frame();
+/// let mut process = grid.into_process(grid::DividerStrategy::Beginning);
+/// let mut lines = Multiline { inner: Truncate };
+/// let v = lines.trim("line one\nline two\n".to_string(), &process, grid::Alignment::Plus);
+/// assert_eq!(vec![TrimmedText::from("line one  ".to_string()), TrimmedText::from("line two  ".to_string())], v);
+/// assert_eq!(lines.back(v, &process, grid::Alignment::Plus), "line one  \nline two  ".to_string());
+/// process.add_to_section("first\nsecond".to_string(), &mut lines, grid::Alignment::Plus).map_err(|_| ())?;
+/// assert_eq!(process.lines_used(grid::Alignment::Plus), 2);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Multiline<T: TrimStrategy<Input = String>> {
+    pub inner: T,
+}
+impl<T: TrimStrategy<Input = String>> Display for Multiline<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<T: TrimStrategy<Input = String>> TrimStrategy for Multiline<T> {
+    type Input = String;
+    fn trim(&mut self, text: String, chunk: &DrawProcess, a: Alignment) -> Vec<TrimmedText> {
+        let mut lines = text.lines().collect::<Vec<_>>();
+        if lines.is_empty() {
+            lines.push("");
+        }
+        if matches!(a, Alignment::Minus) {
+            // The inner strategy reverses each line's rows, so the lines themselves are taken in reverse.
+            lines.reverse();
+        }
+        lines.into_iter().flat_map(|line| self.inner.trim(line.to_string(), chunk, a)).collect()
+    }
+    fn back(&mut self, mut text: Vec<TrimmedText>, chunk: &DrawProcess, a: Alignment) -> Self::Input {
+        if matches!(a, Alignment::Minus) {
+            text.reverse();
+        }
+        let lines = text.into_iter().map(|line| self.inner.back(vec![line], chunk, a)).collect::<Vec<_>>();
+        lines.join("\n")
+    }
+}